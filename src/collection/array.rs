@@ -96,6 +96,13 @@ pub type Bytes32 = Array<u8, 32>;
 ///
 /// Type keeps data in little-endian byte order and displays them in the revers
 /// (like bitcoin SHA256d hash types).
+///
+/// String representation (produced by [`Display`], [`LowerHex`], [`UpperHex`]
+/// and parsed by [`FromStr`]) uses the reversed byte order, while
+/// [`Array::to_byte_array`], indexing and slice access operate on the internal
+/// (non-reversed) byte order. Thus, for a bitcoin transaction id, the string
+/// form matches the txid as shown by block explorers, and the byte array form
+/// matches the raw hash bytes as serialized in the blockchain.
 pub type Bytes32StrRev = Array<u8, 32, true>;
 
 /// Wrapper type for all array-based 512-bit types implementing many important
@@ -154,28 +161,38 @@ impl<T, const LEN: usize, const REVERSE_STR: bool> Array<T, LEN, REVERSE_STR> {
     /// Returns an iterator over the array items.
     ///
     /// The iterator yields all items from start to end.
-    pub fn iter(&self) -> slice::Iter<T> {
+    pub fn iter(&self) -> slice::Iter<'_, T> {
         self.0.iter()
     }
 
     /// Returns an iterator that allows modifying each value.
     ///
     /// The iterator yields all items from start to end.
-    pub fn iter_mut(&mut self) -> slice::IterMut<T> {
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
         self.0.iter_mut()
     }
 }
 
-impl<const LEN: usize, const REVERSE_STR: bool> Array<u8, LEN, REVERSE_STR> {
-    #[cfg(feature = "rand")]
+#[cfg(feature = "rand")]
+impl<T, const LEN: usize, const REVERSE_STR: bool> Array<T, LEN, REVERSE_STR>
+where
+    T: Default + Copy,
+    [T]: rand::Fill,
+{
     /// Generates array from `rand::thread_rng` random number generator
     pub fn random() -> Self {
-        use rand::RngCore;
-        let mut entropy = [0u8; LEN];
-        rand::thread_rng().fill_bytes(&mut entropy);
-        Array::from_inner(entropy)
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    /// Generates array using the provided random number generator
+    pub fn random_with<R: rand::RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut inner = [T::default(); LEN];
+        rand::Rng::fill(rng, &mut inner[..]);
+        Self(inner)
     }
+}
 
+impl<const LEN: usize, const REVERSE_STR: bool> Array<u8, LEN, REVERSE_STR> {
     /// Constructs array filled with zero bytes
     pub const fn zero() -> Self {
         Self([0u8; LEN])
@@ -212,6 +229,45 @@ impl<const LEN: usize, const REVERSE_STR: bool> Array<u8, LEN, REVERSE_STR> {
     pub fn from_byte_array(val: impl Into<[u8; LEN]>) -> Self {
         Array::from_inner(val.into())
     }
+
+    /// Compares two arrays without returning early on the first mismatching
+    /// byte, which is what the derived `==` operator does, leaking the
+    /// position of the first difference through timing.
+    ///
+    /// With the `subtle` feature the comparison is delegated to
+    /// [`subtle::ConstantTimeEq`], which the array also implements and which
+    /// should be preferred for comparing secret data. Without it, the
+    /// comparison is only a best-effort one, since nothing prevents the
+    /// compiler from optimizing it into a non-constant-time code.
+    pub fn ct_eq_bool(&self, other: &Self) -> bool {
+        #[cfg(feature = "subtle")]
+        {
+            subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..]).into()
+        }
+        #[cfg(not(feature = "subtle"))]
+        {
+            let diff = self
+                .0
+                .iter()
+                .zip(&other.0)
+                .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+            core::hint::black_box(diff) == 0
+        }
+    }
+
+    /// Returns the number of set bits across all bytes of the array.
+    pub fn popcount(&self) -> u64 {
+        self.0.iter().map(|byte| byte.count_ones() as u64).sum()
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const LEN: usize, const REVERSE_STR: bool> subtle::ConstantTimeEq
+    for Array<u8, LEN, REVERSE_STR>
+{
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
 }
 
 impl<const LEN: usize, const REVERSE_STR: bool> BitAnd for Array<u8, LEN, REVERSE_STR> {
@@ -309,6 +365,30 @@ where
         inner.copy_from_slice(slice);
         Ok(Self(inner))
     }
+
+    /// Constructs array from the items of an iterator. Errors if the iterator
+    /// yields a number of items different from `LEN`.
+    ///
+    /// The iterator is never advanced more than `LEN + 1` times, so it can be
+    /// infinite; in case of an excess of items the error reports `LEN + 1` as
+    /// the actual length.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, FromSliceError> {
+        let mut iter = iter.into_iter();
+        let mut inner = [T::default(); LEN];
+        for (len, el) in inner.iter_mut().enumerate() {
+            *el = iter.next().ok_or(FromSliceError {
+                actual: len,
+                expected: LEN,
+            })?;
+        }
+        if iter.next().is_some() {
+            return Err(FromSliceError {
+                actual: LEN + 1,
+                expected: LEN,
+            });
+        }
+        Ok(Self(inner))
+    }
 }
 
 impl<T, const LEN: usize, const REVERSE_STR: bool> Default for Array<T, LEN, REVERSE_STR>
@@ -506,6 +586,28 @@ impl<T, const LEN: usize, const REVERSE_STR: bool> IntoIterator for Array<T, LEN
     }
 }
 
+impl<'a, T, const LEN: usize, const REVERSE_STR: bool> IntoIterator
+    for &'a Array<T, LEN, REVERSE_STR>
+{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const LEN: usize, const REVERSE_STR: bool> IntoIterator
+    for &'a mut Array<T, LEN, REVERSE_STR>
+{
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
 impl<T, const LEN: usize, const REVERSE_STR: bool> From<T> for Array<T, LEN, REVERSE_STR>
 where
     T: Into<[T; LEN]>,
@@ -834,6 +936,109 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random() {
+        use rand::SeedableRng;
+
+        let a = Bytes32::random();
+        let b = Bytes32::random();
+        assert_eq!(a.as_slice().len(), 32);
+        assert_ne!(a, b);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let c = Array::<u16, 8>::random_with(&mut rng);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        assert!(Array::<u16, 8>::random_with(&mut rng) == c);
+    }
+
+    #[test]
+    fn test_try_from_iter() {
+        assert_eq!(
+            Bytes4::try_from_iter([1, 2, 3]),
+            Err(FromSliceError {
+                actual: 3,
+                expected: 4
+            })
+        );
+        assert_eq!(Bytes4::try_from_iter(1..=4), Ok(Array([1, 2, 3, 4])));
+        assert_eq!(
+            Bytes4::try_from_iter(0..),
+            Err(FromSliceError {
+                actual: 5,
+                expected: 4
+            })
+        );
+
+        let mut iter = 0u8..;
+        assert!(Bytes4::try_from_iter(&mut iter).is_err());
+        assert_eq!(iter.next(), Some(5));
+    }
+
+    #[test]
+    fn test_slice32_rev_txid() {
+        // Bitcoin genesis block coinbase transaction
+        let txid = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+        let raw = "3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a";
+
+        let id = Bytes32StrRev::from_str(txid).unwrap();
+        assert_eq!(
+            id.to_byte_array(),
+            Bytes32::from_hex(raw).unwrap().to_byte_array()
+        );
+        assert_eq!(id.to_string(), txid);
+        assert_eq!(Bytes32StrRev::from_str(&id.to_string()), Ok(id));
+        assert_eq!(Bytes32StrRev::from_byte_array(id.to_byte_array()), id);
+    }
+
+    #[test]
+    fn test_index_iter() {
+        let mut array = Bytes32::from_array(core::array::from_fn(|i| i as u8));
+        assert_eq!(array[3], 3);
+        assert_eq!(&array[1..4], &[1, 2, 3]);
+        assert_eq!(array.as_slice().len(), 32);
+
+        let mut count = 0usize;
+        for byte in &array {
+            assert_eq!(*byte as usize, count);
+            count += 1;
+        }
+        assert_eq!(count, 32);
+
+        for byte in &mut array {
+            *byte = byte.wrapping_mul(2);
+        }
+        assert_eq!(array[31], 62);
+        assert!(array.iter().eq(array.as_slice()));
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let a = Bytes32::from_array([0xA5; 32]);
+        let mut b = a;
+        assert!(a.ct_eq_bool(&b));
+        assert_eq!(a.ct_eq_bool(&b), a == b);
+        b[31] ^= 1;
+        assert!(!a.ct_eq_bool(&b));
+        assert_eq!(a.ct_eq_bool(&b), a == b);
+        assert!(!a.ct_eq_bool(&Bytes32::zero()));
+        #[cfg(feature = "subtle")]
+        {
+            assert!(!bool::from(subtle::ConstantTimeEq::ct_eq(&a, &b)));
+            assert!(bool::from(subtle::ConstantTimeEq::ct_eq(&a, &a)));
+            use subtle::ConstantTimeEq;
+            let choice: subtle::Choice = a.ct_eq(&b);
+            assert!(!bool::from(choice));
+        }
+    }
+
+    #[test]
+    fn test_popcount() {
+        assert_eq!(Bytes32::zero().popcount(), 0);
+        assert_eq!(Bytes32::from_array([0xFF; 32]).popcount(), 256);
+        assert_eq!(Bytes4::from_array([0x01, 0x03, 0xF0, 0x80]).popcount(), 8);
+    }
+
     #[test]
     fn test_encoding() {
         let s = "a3401bcceb26201b55978ff705fecf7d8a0a03598ebeccf2a947030b91a0ff53";
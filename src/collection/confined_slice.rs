@@ -0,0 +1,183 @@
+// Rust language amplification library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2022-2024 by
+//     Dr. Maxim Orlovsky <orlovsky@ubideco.org>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Zero-copy view into a slice with a confined number of elements.
+
+use core::ops::{Deref, Index};
+use core::slice::{self, SliceIndex};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::confinement_error::{check_len, Error};
+#[cfg(feature = "alloc")]
+use crate::confinement::Confined;
+
+/// Borrowed slice which length is guaranteed to be within `MIN_LEN..=MAX_LEN`
+/// bounds. This is a zero-copy counterpart of `Confined` vectors, allowing
+/// to pass already length-validated data around without allocation.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ConfinedSlice<'a, T, const MIN_LEN: usize, const MAX_LEN: usize>(&'a [T]);
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Clone
+    for ConfinedSlice<'_, T, MIN_LEN, MAX_LEN>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Copy
+    for ConfinedSlice<'_, T, MIN_LEN, MAX_LEN>
+{
+}
+
+impl<'a, T, const MIN_LEN: usize, const MAX_LEN: usize> ConfinedSlice<'a, T, MIN_LEN, MAX_LEN> {
+    /// Constructs confined view into a slice. Errors if the slice length
+    /// doesn't fit the confinement bounds.
+    pub fn try_from_slice(slice: &'a [T]) -> Result<Self, Error> {
+        check_len::<MIN_LEN, MAX_LEN>(slice.len())?;
+        Ok(Self(slice))
+    }
+
+    /// Returns the underlying slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.0
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Detects whether the slice is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the slice.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'a, T> {
+        self.0.iter()
+    }
+
+    /// Returns the first element of the slice, or [`None`] if it is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&'a T> {
+        self.0.first()
+    }
+
+    /// Returns the last element of the slice, or [`None`] if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&'a T> {
+        self.0.last()
+    }
+
+    /// Gets the element or a subslice, or [`None`] if the index is out of
+    /// bounds.
+    #[inline]
+    pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&'a I::Output> {
+        self.0.get(index)
+    }
+
+    /// Clones the slice into an owned confined vector with the same bounds.
+    #[cfg(feature = "alloc")]
+    pub fn to_confined(&self) -> Confined<Vec<T>, MIN_LEN, MAX_LEN>
+    where
+        T: Clone,
+    {
+        Confined::from_checked(self.0.to_vec())
+    }
+}
+
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Deref
+    for ConfinedSlice<'_, T, MIN_LEN, MAX_LEN>
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const MIN_LEN: usize, const MAX_LEN: usize> Index<I>
+    for ConfinedSlice<'_, T, MIN_LEN, MAX_LEN>
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<'a, T, const MIN_LEN: usize, const MAX_LEN: usize> IntoIterator
+    for ConfinedSlice<'a, T, MIN_LEN, MAX_LEN>
+{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, const MIN_LEN: usize, const MAX_LEN: usize> From<&'a Confined<Vec<T>, MIN_LEN, MAX_LEN>>
+    for ConfinedSlice<'a, T, MIN_LEN, MAX_LEN>
+{
+    fn from(confined: &'a Confined<Vec<T>, MIN_LEN, MAX_LEN>) -> Self {
+        Self(confined.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn try_from_slice() {
+        let data = [1u8, 2, 3, 4];
+        let slice = ConfinedSlice::<u8, 1, 4>::try_from_slice(&data).unwrap();
+        assert_eq!(slice.len(), 4);
+        assert_eq!(slice[1], 2);
+        assert_eq!(&slice[1..3], &[2, 3]);
+        assert_eq!(slice.first(), Some(&1));
+        assert_eq!(slice.last(), Some(&4));
+        assert_eq!(slice.iter().sum::<u8>(), 10);
+
+        assert_eq!(
+            ConfinedSlice::<u8, 1, 3>::try_from_slice(&data),
+            Err(Error::Oversize { len: 4, max_len: 3 })
+        );
+        assert_eq!(
+            ConfinedSlice::<u8, 1, 3>::try_from_slice(&data[..0]),
+            Err(Error::Undersize { len: 0, min_len: 1 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_confined() {
+        use crate::confinement::NonEmptyVec;
+
+        let vec = NonEmptyVec::<u8, 4>::try_from(vec![5, 6]).unwrap();
+        let slice = ConfinedSlice::from(&vec);
+        assert_eq!(slice.as_slice(), vec.as_slice());
+        assert_eq!(slice.into_iter().copied().collect::<Vec<_>>(), vec![5, 6]);
+        assert_eq!(slice.to_confined(), vec);
+    }
+}
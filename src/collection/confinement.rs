@@ -20,10 +20,11 @@ use core::fmt::{self, Display, Formatter, LowerHex, UpperHex};
 use core::str::FromStr;
 use core::hash::Hash;
 use core::ops::{
-    Deref, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+    Deref, DerefMut, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
 };
 use alloc::vec::Vec;
-use alloc::string::String;
+use alloc::string::{FromUtf8Error, String};
 use alloc::borrow::ToOwned;
 use alloc::collections::{btree_map, BTreeMap, BTreeSet, VecDeque};
 use alloc::collections::vec_deque::Drain;
@@ -36,7 +37,7 @@ use std::{
 };
 use amplify_num::hex;
 use amplify_num::hex::{FromHex, ToHex};
-use ascii::{AsAsciiStrError, AsciiChar, AsciiString};
+use ascii::{AsAsciiStrError, AsciiChar, AsciiStr, AsciiString};
 
 use crate::num::u24;
 
@@ -346,62 +347,8 @@ impl<K: Ord + Hash, V> KeyedCollection for BTreeMap<K, V> {
 
 // Errors
 
-/// Errors when confinement constraints were not met.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub enum Error {
-    /// Operation results in collection reduced below the required minimum
-    /// number of elements.
-    Undersize {
-        /** Current collection length */
-        len: usize,
-        /** Minimum number of elements which must be present in the
-         * collection */
-        min_len: usize,
-    },
-
-    /// Operation results in collection growth above the required maximum number
-    /// of elements.
-    Oversize {
-        /** Current collection length */
-        len: usize,
-        /** Maximum number of elements which must be present in the
-         * collection */
-        max_len: usize,
-    },
-
-    /// Attempt to address an index outside the collection bounds.
-    OutOfBoundary {
-        /** Index which was outside the bounds */
-        index: usize,
-        /** The actual number of elements in the collection */
-        len: usize,
-    },
-}
-
-impl Display for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            Error::Undersize { len, min_len } => write!(
-                f,
-                "operation results in collection size {len} less than lower boundary \
-                 of {min_len}, which is prohibited"
-            ),
-            Error::Oversize { len, max_len } => write!(
-                f,
-                "operation results in collection size {len} exceeding {max_len}, \
-                which is prohibited"
-            ),
-            Error::OutOfBoundary { index, len } => write!(
-                f,
-                "attempt to access the element at {index} which is outside of the \
-                collection length boundary {len}"
-            ),
-        }
-    }
-}
-
-#[cfg(feature = "std")]
-impl std::error::Error for Error {}
+pub use super::confinement_error::Error;
+use super::confinement_error::check_len;
 
 /// Errors generated by constructing confined [`AsciiString`] from `str`.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -437,6 +384,70 @@ impl Display for AsciiError {
 #[cfg(feature = "std")]
 impl std::error::Error for AsciiError {}
 
+/// Errors generated by constructing confined [`String`] from UTF-8 bytes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Utf8Error {
+    /// The bytes are not a valid UTF-8 string
+    Utf8(FromUtf8Error),
+
+    /// Confinement requirements are violated
+    Confinement(Error),
+}
+
+impl From<FromUtf8Error> for Utf8Error {
+    fn from(err: FromUtf8Error) -> Self {
+        Utf8Error::Utf8(err)
+    }
+}
+
+impl From<Error> for Utf8Error {
+    fn from(err: Error) -> Self {
+        Utf8Error::Confinement(err)
+    }
+}
+
+impl Display for Utf8Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Utf8Error::Utf8(e) => Display::fmt(e, f),
+            Utf8Error::Confinement(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf8Error {}
+
+/// Errors generated by inserting into a confined [`String`] at a byte index.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StrInsertError {
+    /// The byte index doesn't lie on a UTF-8 character boundary
+    NotCharBoundary(usize),
+
+    /// Confinement requirements are violated
+    Confinement(Error),
+}
+
+impl From<Error> for StrInsertError {
+    fn from(err: Error) -> Self {
+        StrInsertError::Confinement(err)
+    }
+}
+
+impl Display for StrInsertError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StrInsertError::NotCharBoundary(index) => {
+                write!(f, "byte index {index} is not a string character boundary")
+            }
+            StrInsertError::Confinement(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrInsertError {}
+
 // Confinement params
 
 /// Constant for a minimal size of a confined collection.
@@ -781,22 +792,16 @@ impl<C: Collection, const MIN_LEN: usize, const MAX_LEN: usize> Confined<C, MIN_
     // We can't use `impl TryFrom` due to the conflict with core library blanked
     // implementation
     pub fn try_from(col: C) -> Result<Self, Error> {
-        let len = col.len();
-        if len < MIN_LEN {
-            return Err(Error::Undersize {
-                len,
-                min_len: MIN_LEN,
-            });
-        }
-        if len > MAX_LEN {
-            return Err(Error::Oversize {
-                len,
-                max_len: MAX_LEN,
-            });
-        }
+        Self::check_len(col.len())?;
         Ok(Self(col))
     }
 
+    /// Checks whether a collection of a given length fits the confinement
+    /// bounds.
+    fn check_len(len: usize) -> Result<(), Error> {
+        check_len::<MIN_LEN, MAX_LEN>(len)
+    }
+
     /// Tries to construct a confinement with a collection of elements taken
     /// from an iterator. Fails if the number of items in the collection
     /// exceeds one of the confinement bounds.
@@ -835,6 +840,24 @@ impl<C: Collection, const MIN_LEN: usize, const MAX_LEN: usize> Confined<C, MIN_
         &self.0
     }
 
+    /// Provides mutable access to a copy of the inner collection through a
+    /// guard, which re-validates the confinement bounds before writing the
+    /// modified collection back.
+    ///
+    /// The modification gets applied only by [`ConfinedMutGuard::commit`], and
+    /// only if the modified collection fits the confinement bounds. Any
+    /// changes made after the last successful commit are discarded once the
+    /// guard is dropped, leaked or unwound through.
+    pub fn modify(&mut self) -> ConfinedMutGuard<'_, C, MIN_LEN, MAX_LEN>
+    where
+        C: Clone,
+    {
+        ConfinedMutGuard {
+            working: self.0.clone(),
+            confined: self,
+        }
+    }
+
     /// Clones inner collection type and returns it
     #[deprecated(since = "4.7.0", note = "use to_unconfined method")]
     pub fn to_inner(&self) -> C
@@ -892,6 +915,80 @@ impl<C: Collection, const MIN_LEN: usize, const MAX_LEN: usize> Confined<C, MIN_
     pub fn release(self) -> C {
         self.0
     }
+
+    /// Converts into a confined collection with different bounds, checking
+    /// that the collection length fits them.
+    pub fn narrow<const MIN2: usize, const MAX2: usize>(
+        self,
+    ) -> Result<Confined<C, MIN2, MAX2>, Error> {
+        Confined::try_from(self.0)
+    }
+
+    /// Converts into a confined collection with looser bounds, which are
+    /// guaranteed to fit the collection length. Requires `MIN2 <= MIN_LEN`
+    /// and `MAX2 >= MAX_LEN`, which is checked at compile time:
+    ///
+    /// ```
+    /// # use amplify::confinement::{NonEmptyVec, SmallVec, TinyVec};
+    /// let vec = NonEmptyVec::<u8, 16>::with(1);
+    /// let vec: TinyVec<u8> = vec.widen();
+    /// let vec: SmallVec<u8> = vec.widen();
+    /// assert_eq!(vec.len(), 1);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use amplify::confinement::{SmallVec, TinyVec};
+    /// let vec: TinyVec<u8> = SmallVec::<u8>::new().widen();
+    /// ```
+    pub fn widen<const MIN2: usize, const MAX2: usize>(self) -> Confined<C, MIN2, MAX2> {
+        let () = WidenCheck::<MIN_LEN, MAX_LEN, MIN2, MAX2>::VALID;
+        Confined(self.0)
+    }
+}
+
+/// Guard providing mutable access to a working copy of the collection inside
+/// [`Confined`], returned by [`Confined::modify`].
+///
+/// The confined collection itself is never modified directly: the working
+/// copy is written back only by [`ConfinedMutGuard::commit`], and only if it
+/// fits the confinement bounds. Uncommitted changes are discarded when the
+/// guard is dropped, thus neither leaking the guard nor unwinding through it
+/// may break the confinement invariant or apply a partial modification.
+pub struct ConfinedMutGuard<'a, C: Collection, const MIN_LEN: usize, const MAX_LEN: usize> {
+    confined: &'a mut Confined<C, MIN_LEN, MAX_LEN>,
+    working: C,
+}
+
+impl<C: Collection + Clone, const MIN_LEN: usize, const MAX_LEN: usize>
+    ConfinedMutGuard<'_, C, MIN_LEN, MAX_LEN>
+{
+    /// Checks that the modified collection fits the confinement bounds and
+    /// writes it back to the confined collection. Errors otherwise, in which
+    /// case the confined collection is left unchanged and the working copy may
+    /// be further modified through the guard to restore the bounds.
+    pub fn commit(&mut self) -> Result<(), Error> {
+        Confined::<C, MIN_LEN, MAX_LEN>::check_len(self.working.len())?;
+        self.confined.0.clone_from(&self.working);
+        Ok(())
+    }
+}
+
+impl<C: Collection, const MIN_LEN: usize, const MAX_LEN: usize> Deref
+    for ConfinedMutGuard<'_, C, MIN_LEN, MAX_LEN>
+{
+    type Target = C;
+
+    fn deref(&self) -> &Self::Target {
+        &self.working
+    }
+}
+
+impl<C: Collection, const MIN_LEN: usize, const MAX_LEN: usize> DerefMut
+    for ConfinedMutGuard<'_, C, MIN_LEN, MAX_LEN>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.working
+    }
 }
 
 impl<C: Collection, const MAX_LEN: usize> Confined<C, ZERO, MAX_LEN>
@@ -1054,6 +1151,68 @@ impl<const MIN_LEN: usize, const MAX_LEN: usize> TryFrom<&str>
     }
 }
 
+/// Compile-time check that an array of `LEN` items fits confinement bounds.
+struct ArrayLenCheck<const LEN: usize, const MIN_LEN: usize, const MAX_LEN: usize>;
+
+impl<const LEN: usize, const MIN_LEN: usize, const MAX_LEN: usize>
+    ArrayLenCheck<LEN, MIN_LEN, MAX_LEN>
+{
+    const VALID: () = assert!(
+        LEN >= MIN_LEN && LEN <= MAX_LEN,
+        "array length doesn't fit confinement bounds"
+    );
+}
+
+/// Compile-time check that confinement bounds `MIN2..=MAX2` include bounds
+/// `MIN_LEN..=MAX_LEN`.
+struct WidenCheck<const MIN_LEN: usize, const MAX_LEN: usize, const MIN2: usize, const MAX2: usize>;
+
+impl<const MIN_LEN: usize, const MAX_LEN: usize, const MIN2: usize, const MAX2: usize>
+    WidenCheck<MIN_LEN, MAX_LEN, MIN2, MAX2>
+{
+    const VALID: () = assert!(
+        MIN2 <= MIN_LEN && MAX2 >= MAX_LEN,
+        "confinement bounds can't be widened to narrower ones"
+    );
+}
+
+/// Compile-time check that confinement bounds guarantee a non-empty collection.
+struct NonEmptyCheck<const MIN_LEN: usize>;
+
+impl<const MIN_LEN: usize> NonEmptyCheck<MIN_LEN> {
+    const VALID: () = assert!(MIN_LEN >= 1, "confined collection may be empty");
+}
+
+/// Converts a fixed-size array into a confined vector. The array length is
+/// checked against the confinement bounds at compile time, so the conversion
+/// never fails at runtime.
+///
+/// ```
+/// # use amplify::confinement::NonEmptyVec;
+/// let vec = NonEmptyVec::<u8, 4>::from([1, 2, 3]);
+/// assert_eq!(vec.len(), 3);
+/// ```
+///
+/// Arrays which don't fit the bounds are rejected by the compiler:
+///
+/// ```compile_fail
+/// # use amplify::confinement::NonEmptyVec;
+/// let vec = NonEmptyVec::<u8, 2>::from([1, 2, 3]);
+/// ```
+///
+/// ```compile_fail
+/// # use amplify::confinement::NonEmptyVec;
+/// let vec = NonEmptyVec::<u8, 2>::from([]);
+/// ```
+impl<T, const LEN: usize, const MIN_LEN: usize, const MAX_LEN: usize> From<[T; LEN]>
+    for Confined<Vec<T>, MIN_LEN, MAX_LEN>
+{
+    fn from(array: [T; LEN]) -> Self {
+        let () = ArrayLenCheck::<LEN, MIN_LEN, MAX_LEN>::VALID;
+        Self(Vec::from(array))
+    }
+}
+
 impl<const MAX_LEN: usize> Confined<String, ZERO, MAX_LEN> {
     /// Removes the last character from a string and returns it, or [`None`] if
     /// it is empty.
@@ -1063,6 +1222,23 @@ impl<const MAX_LEN: usize> Confined<String, ZERO, MAX_LEN> {
 }
 
 impl<const MIN_LEN: usize, const MAX_LEN: usize> Confined<String, MIN_LEN, MAX_LEN> {
+    /// Constructs confinement out of a string, truncating it to `MAX_LEN`
+    /// bytes if it is longer. If `MAX_LEN` falls inside a multibyte character,
+    /// the whole character is dropped.
+    ///
+    /// This conversion is lossy: all data beyond the confinement limit is
+    /// silently discarded. Errors if the string is shorter than `MIN_LEN`.
+    pub fn from_truncated(mut s: String) -> Result<Self, Error> {
+        if s.len() > MAX_LEN {
+            let mut len = MAX_LEN;
+            while !s.is_char_boundary(len) {
+                len -= 1;
+            }
+            s.truncate(len);
+        }
+        Self::try_from(s)
+    }
+
     /// Removes a single character from the confined string, unless the string
     /// doesn't shorten more than the confinement requirement. Errors
     /// otherwise.
@@ -1079,6 +1255,100 @@ impl<const MIN_LEN: usize, const MAX_LEN: usize> Confined<String, MIN_LEN, MAX_L
         }
         Ok(self.0.remove(index))
     }
+
+    /// Constructs confined string from a vector of UTF-8 bytes. Errors if the
+    /// bytes are not valid UTF-8 or if their number doesn't fit the
+    /// confinement bounds.
+    pub fn from_utf8(bytes: Vec<u8>) -> Result<Self, Utf8Error> {
+        let s = String::from_utf8(bytes)?;
+        Self::try_from(s).map_err(Utf8Error::from)
+    }
+
+    /// Constructs confined string from a slice of bytes, replacing invalid
+    /// UTF-8 sequences with [`char::REPLACEMENT_CHARACTER`]. Since each
+    /// replacement character takes three bytes, the resulting string may be
+    /// longer than the source slice. Errors if the length of the resulting
+    /// string doesn't fit the confinement bounds.
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    /// Converts the string into a confined vector of its UTF-8 bytes.
+    ///
+    /// The confinement bounds carry over as-is: they are always applied to the
+    /// string length in bytes (and not in characters), so the byte vector is
+    /// guaranteed to satisfy them.
+    pub fn into_bytes(self) -> Confined<Vec<u8>, MIN_LEN, MAX_LEN> {
+        Confined(self.0.into_bytes())
+    }
+
+    /// Inserts a character into the string at a given byte index. Errors if
+    /// the new string length exceeds the confinement requirement, if the index
+    /// is greater than the string length or if it doesn't lie on a character
+    /// boundary.
+    pub fn insert(&mut self, index: usize, ch: char) -> Result<(), StrInsertError> {
+        self.check_insert(index, ch.len_utf8())?;
+        self.0.insert(index, ch);
+        Ok(())
+    }
+
+    /// Inserts a string slice into the string at a given byte index. Errors if
+    /// the new string length exceeds the confinement requirement, if the index
+    /// is greater than the string length or if it doesn't lie on a character
+    /// boundary.
+    pub fn insert_str(&mut self, index: usize, s: &str) -> Result<(), StrInsertError> {
+        self.check_insert(index, s.len())?;
+        self.0.insert_str(index, s);
+        Ok(())
+    }
+
+    fn check_insert(&self, index: usize, add_len: usize) -> Result<(), StrInsertError> {
+        let len = self.len();
+        if index > len {
+            return Err(Error::OutOfBoundary { index, len }.into());
+        }
+        if !self.0.is_char_boundary(index) {
+            return Err(StrInsertError::NotCharBoundary(index));
+        }
+        if len + add_len > MAX_LEN {
+            return Err(Error::Oversize {
+                len: len + add_len,
+                max_len: MAX_LEN,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Returns a string slice with leading and trailing whitespace removed.
+    pub fn trim(&self) -> &str {
+        self.0.trim()
+    }
+
+    /// Returns a string slice with leading whitespace removed.
+    pub fn trim_start(&self) -> &str {
+        self.0.trim_start()
+    }
+
+    /// Returns a string slice with trailing whitespace removed.
+    pub fn trim_end(&self) -> &str {
+        self.0.trim_end()
+    }
+
+    /// Returns `true` if the string starts with the given prefix.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.0.starts_with(prefix)
+    }
+
+    /// Returns `true` if the string ends with the given suffix.
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.0.ends_with(suffix)
+    }
+
+    /// Returns `true` if the string contains the given pattern.
+    pub fn contains(&self, pat: &str) -> bool {
+        self.0.contains(pat)
+    }
 }
 
 impl<const MAX_LEN: usize> Confined<AsciiString, ZERO, MAX_LEN> {
@@ -1106,6 +1376,37 @@ impl<const MIN_LEN: usize, const MAX_LEN: usize> Confined<AsciiString, MIN_LEN,
         }
         Ok(self.0.remove(index))
     }
+
+    /// Returns an ASCII string slice with leading and trailing whitespace
+    /// removed.
+    pub fn trim(&self) -> &AsciiStr {
+        self.0.trim()
+    }
+
+    /// Returns an ASCII string slice with leading whitespace removed.
+    pub fn trim_start(&self) -> &AsciiStr {
+        self.0.trim_start()
+    }
+
+    /// Returns an ASCII string slice with trailing whitespace removed.
+    pub fn trim_end(&self) -> &AsciiStr {
+        self.0.trim_end()
+    }
+
+    /// Returns `true` if the string starts with the given prefix.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.0.as_str().starts_with(prefix)
+    }
+
+    /// Returns `true` if the string ends with the given suffix.
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.0.as_str().ends_with(suffix)
+    }
+
+    /// Returns `true` if the string contains the given pattern.
+    pub fn contains(&self, pat: &str) -> bool {
+        self.0.as_str().contains(pat)
+    }
 }
 
 impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<Vec<T>, MIN_LEN, MAX_LEN> {
@@ -1154,6 +1455,66 @@ impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<Vec<T>, MIN_LEN, MA
         self.0
     }
 
+    /// Gets the element or a subslice of a vector, or [`None`] if the index is
+    /// out of bounds.
+    #[inline]
+    pub fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        self.0.get(index)
+    }
+
+    /// Returns the first element of the vector, or [`None`] if it is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.0.first()
+    }
+
+    /// Returns the last element of the vector, or [`None`] if it is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.0.last()
+    }
+
+    /// Returns the first element of a vector which can't be empty. Unlike
+    /// [`Self::first`], never fails; requires `MIN_LEN >= 1`, which is checked
+    /// at compile time:
+    ///
+    /// ```
+    /// # use amplify::confinement::{Confined, NonEmptyVec};
+    /// let vec = NonEmptyVec::<u8, 4>::with(1);
+    /// assert_eq!(vec.first_item(), &1);
+    /// let vec = Confined::<Vec<u8>, 2, 4>::try_from(vec![1, 2]).unwrap();
+    /// assert_eq!(vec.first_item(), &1);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use amplify::confinement::TinyVec;
+    /// let vec = TinyVec::<u8>::new();
+    /// vec.first_item();
+    /// ```
+    #[inline]
+    pub fn first_item(&self) -> &T {
+        let () = NonEmptyCheck::<MIN_LEN>::VALID;
+        &self.0[0]
+    }
+
+    /// Returns the last element of a vector which can't be empty. Unlike
+    /// [`Self::last`], never fails; requires `MIN_LEN >= 1`, which is checked
+    /// at compile time:
+    ///
+    /// ```compile_fail
+    /// # use amplify::confinement::TinyVec;
+    /// let vec = TinyVec::<u8>::new();
+    /// vec.last_item();
+    /// ```
+    #[inline]
+    pub fn last_item(&self) -> &T {
+        let () = NonEmptyCheck::<MIN_LEN>::VALID;
+        &self.0[self.0.len() - 1]
+    }
+
     /// Gets the mutable element of a vector
     #[inline]
     pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
@@ -1174,6 +1535,16 @@ impl<T, const MAX_LEN: usize> Confined<Vec<T>, ZERO, MAX_LEN> {
 }
 
 impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<Vec<T>, MIN_LEN, MAX_LEN> {
+    /// Constructs confinement out of a vector, keeping only its first
+    /// `MAX_LEN` elements.
+    ///
+    /// This conversion is lossy: all elements beyond the confinement limit are
+    /// silently dropped. Errors if the vector is shorter than `MIN_LEN`.
+    pub fn from_truncated(mut vec: Vec<T>) -> Result<Self, Error> {
+        vec.truncate(MAX_LEN);
+        Self::try_from(vec)
+    }
+
     /// Removes an element from the vector at a given index. Errors if the index
     /// exceeds the number of elements in the vector, of if the new vector
     /// length will be less than the confinement requirement. Returns the
@@ -1192,12 +1563,272 @@ impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<Vec<T>, MIN_LEN, MA
         Ok(self.0.remove(index))
     }
 
+    /// Inserts an element at position `index` within the vector, shifting all
+    /// elements after it to the right. Errors if the index exceeds the number
+    /// of elements in the vector, or if the new vector length will exceed the
+    /// confinement requirement.
+    pub fn insert(&mut self, index: usize, element: T) -> Result<(), Error> {
+        let len = self.len();
+        if index > len {
+            return Err(Error::OutOfBoundary { index, len });
+        }
+        if len == MAX_LEN || len + 1 > MAX_LEN {
+            return Err(Error::Oversize {
+                len: len + 1,
+                max_len: MAX_LEN,
+            });
+        }
+        self.0.insert(index, element);
+        Ok(())
+    }
+
+    /// Removes an element from the vector at a given index, replacing it with
+    /// the last element of the vector. This does not preserve ordering, but is
+    /// O(1). Errors if the index exceeds the number of elements in the vector,
+    /// of if the new vector length will be less than the confinement
+    /// requirement. Returns the removed element otherwise.
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, Error> {
+        let len = self.len();
+        if self.is_empty() || len <= MIN_LEN {
+            return Err(Error::Undersize {
+                len,
+                min_len: MIN_LEN,
+            });
+        }
+        if index >= len {
+            return Err(Error::OutOfBoundary { index, len });
+        }
+        Ok(self.0.swap_remove(index))
+    }
+
+    /// Removes consecutive repeated elements in the vector according to the
+    /// [`PartialEq`] trait implementation.
+    ///
+    /// Errors if the deduplicated vector will be shorter than the confinement
+    /// requirement; in this case the vector is not modified.
+    pub fn dedup(&mut self) -> Result<(), Error>
+    where
+        T: PartialEq,
+    {
+        let len = match self.len() {
+            0 => 0,
+            _ => 1 + self.0.windows(2).filter(|w| w[0] != w[1]).count(),
+        };
+        if len < MIN_LEN {
+            return Err(Error::Undersize {
+                len,
+                min_len: MIN_LEN,
+            });
+        }
+        self.0.dedup();
+        Ok(())
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that
+    /// resolve to the same key. The `key` function is called exactly once for
+    /// each element.
+    ///
+    /// Errors if the deduplicated vector will be shorter than the confinement
+    /// requirement; in this case the vector is not modified.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        let keys = self.0.iter_mut().map(&mut key).collect::<Vec<_>>();
+        let keep = (0..keys.len())
+            .map(|i| i == 0 || keys[i] != keys[i - 1])
+            .collect::<Vec<_>>();
+        let len = keep.iter().filter(|keep| **keep).count();
+        if len < MIN_LEN {
+            return Err(Error::Undersize {
+                len,
+                min_len: MIN_LEN,
+            });
+        }
+        let mut keep = keep.into_iter();
+        self.0.retain(|_| keep.next().unwrap_or_default());
+        Ok(())
+    }
+
     /// Returns an iterator over the slice.
     ///
     /// The iterator yields all items from start to end.
-    pub fn iter(&self) -> core::slice::Iter<T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.0.iter()
     }
+
+    /// Returns an iterator over `chunk_size` elements of the vector at a time,
+    /// starting at the beginning of the vector. The last chunk may be shorter
+    /// if the vector length is not divisible by `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn chunks(&self, chunk_size: usize) -> core::slice::Chunks<'_, T> {
+        self.0.chunks(chunk_size)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`. The
+    /// windows overlap. If the vector is shorter than `size`, the iterator
+    /// returns no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.0.windows(size)
+    }
+
+    /// Searches for an element in the vector, returning its index.
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.0.iter().position(pred)
+    }
+
+    /// Searches for an element in the vector that satisfies a predicate.
+    pub fn find<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<&T> {
+        self.0.iter().find(|item| pred(item))
+    }
+
+    /// Splits the vector into two at the given index, keeping elements
+    /// `[0, at)` in `self` and returning elements `[at, len)` as a new
+    /// confined vector.
+    ///
+    /// The returned suffix has zero minimal length, since it may be shorter
+    /// than the original `MIN_LEN`. Errors with [`Error::OutOfBoundary`] if
+    /// `at > len`, or with [`Error::Undersize`] if the remaining prefix would
+    /// become shorter than `MIN_LEN`.
+    pub fn split_off(&mut self, at: usize) -> Result<Confined<Vec<T>, ZERO, MAX_LEN>, Error> {
+        let len = self.len();
+        if at > len {
+            return Err(Error::OutOfBoundary { index: at, len });
+        }
+        if at < MIN_LEN {
+            return Err(Error::Undersize {
+                len: at,
+                min_len: MIN_LEN,
+            });
+        }
+        Ok(Confined(self.0.split_off(at)))
+    }
+
+    /// Extends the vector with the items from an exact-size iterator. The
+    /// resulting length is checked once against `MAX_LEN` before any item is
+    /// added, so the vector is left unchanged on error.
+    ///
+    /// If the iterator yields more items than its reported length, the excess
+    /// items are ignored.
+    pub fn try_extend_exact<I>(&mut self, iter: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let add = iter.len();
+        let len = self.len() + add;
+        if len > MAX_LEN {
+            return Err(Error::Oversize {
+                len,
+                max_len: MAX_LEN,
+            });
+        }
+        self.0.extend(iter.take(add));
+        Ok(())
+    }
+
+    /// Clones and appends all elements in a slice to the vector.
+    ///
+    /// Unlike [`Confined::extend`], the resulting length is checked once
+    /// before any modification, so the vector is left unchanged if it exceeds
+    /// `MAX_LEN`.
+    pub fn extend_from_slice(&mut self, slice: &[T]) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        let len = self.len() + slice.len();
+        if len > MAX_LEN {
+            return Err(Error::Oversize {
+                len,
+                max_len: MAX_LEN,
+            });
+        }
+        self.0.extend_from_slice(slice);
+        Ok(())
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// Errors if the combined length exceeds `MAX_LEN`; in this case neither
+    /// of the vectors is modified.
+    pub fn append<const MAX2: usize>(
+        &mut self,
+        other: &mut Confined<Vec<T>, ZERO, MAX2>,
+    ) -> Result<(), Error> {
+        let len = self.len() + other.len();
+        if len > MAX_LEN {
+            return Err(Error::Oversize {
+                len,
+                max_len: MAX_LEN,
+            });
+        }
+        self.0.append(&mut other.0);
+        Ok(())
+    }
+
+    /// Resizes the vector in-place so that its length is equal to `new_len`,
+    /// filling new slots with clones of `value` or truncating it.
+    ///
+    /// Errors if `new_len` doesn't fit the confinement bounds; in this case the
+    /// vector is not modified.
+    pub fn resize(&mut self, new_len: usize, value: T) -> Result<(), Error>
+    where
+        T: Clone,
+    {
+        Self::check_len(new_len)?;
+        self.0.resize(new_len, value);
+        Ok(())
+    }
+
+    /// Resizes the vector in-place so that its length is equal to `new_len`,
+    /// filling new slots with values returned by calling the closure `f` or
+    /// truncating it.
+    ///
+    /// Errors if `new_len` doesn't fit the confinement bounds; in this case the
+    /// vector is not modified.
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, f: F) -> Result<(), Error> {
+        Self::check_len(new_len)?;
+        self.0.resize_with(new_len, f);
+        Ok(())
+    }
+
+    /// Transforms each element of the vector, producing a confined vector of
+    /// the same length and confinement bounds.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Confined<Vec<U>, MIN_LEN, MAX_LEN> {
+        Confined::from_checked(self.0.into_iter().map(f).collect())
+    }
+
+    /// Passes an iterator over the vector elements through a transformation
+    /// pipeline `f` and collects the results into a new confined vector with
+    /// the same bounds. Unlike [`Self::map`], the pipeline may change the
+    /// number of elements, so the result length is checked.
+    pub fn try_collect<U, I, F>(self, f: F) -> Result<Confined<Vec<U>, MIN_LEN, MAX_LEN>, Error>
+    where
+        F: FnOnce(alloc::vec::IntoIter<T>) -> I,
+        I: IntoIterator<Item = U>,
+    {
+        Confined::try_from_iter(f(self.0.into_iter()))
+    }
+
+    /// Tries to transform each element of the vector, producing a confined
+    /// vector of the same length and confinement bounds. Stops on the first
+    /// error and returns it.
+    pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(
+        self,
+        f: F,
+    ) -> Result<Confined<Vec<U>, MIN_LEN, MAX_LEN>, E> {
+        let vec = self.0.into_iter().map(f).collect::<Result<_, _>>()?;
+        Ok(Confined::from_checked(vec))
+    }
 }
 
 impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<VecDeque<T>, MIN_LEN, MAX_LEN> {
@@ -1214,7 +1845,31 @@ impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<VecDeque<T>, MIN_LE
     }
 }
 
-impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<VecDeque<T>, MIN_LEN, MAX_LEN> {
+impl<T, const MIN_LEN: usize, const MAX_LEN: usize> Confined<VecDeque<T>, MIN_LEN, MAX_LEN> {
+    /// Extends the deque with the items from an exact-size iterator. The
+    /// resulting length is checked once against `MAX_LEN` before any item is
+    /// added, so the deque is left unchanged on error.
+    ///
+    /// If the iterator yields more items than its reported length, the excess
+    /// items are ignored.
+    pub fn try_extend_exact<I>(&mut self, iter: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        let add = iter.len();
+        let len = self.len() + add;
+        if len > MAX_LEN {
+            return Err(Error::Oversize {
+                len,
+                max_len: MAX_LEN,
+            });
+        }
+        self.0.extend(iter.take(add));
+        Ok(())
+    }
+
     /// Prepends an element to the deque. Errors if the new collection length
     /// will not fit the confinement requirements.
     pub fn push_front(&mut self, elem: T) -> Result<(), Error> {
@@ -1374,6 +2029,62 @@ impl<T: Ord, const MIN_LEN: usize, const MAX_LEN: usize> Confined<BTreeSet<T>, M
         }
         Ok(self.0.take(elem))
     }
+
+    /// Removes the first (minimal) element from the set and returns it.
+    /// Returns `None` if the set is empty, and errors without modifying the
+    /// set if the new collection length will be less than the confinement
+    /// requirement.
+    pub fn pop_first(&mut self) -> Result<Option<T>, Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let len = self.len();
+        if len <= MIN_LEN {
+            return Err(Error::Undersize {
+                len,
+                min_len: MIN_LEN,
+            });
+        }
+        Ok(self.0.pop_first())
+    }
+
+    /// Removes the last (maximal) element from the set and returns it.
+    /// Returns `None` if the set is empty, and errors without modifying the
+    /// set if the new collection length will be less than the confinement
+    /// requirement.
+    pub fn pop_last(&mut self) -> Result<Option<T>, Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let len = self.len();
+        if len <= MIN_LEN {
+            return Err(Error::Undersize {
+                len,
+                min_len: MIN_LEN,
+            });
+        }
+        Ok(self.0.pop_last())
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// Elements already present in `self` are not duplicated, so only the
+    /// size of the union is checked against `MAX_LEN`. Errors if the union
+    /// exceeds the limit; in this case neither of the sets is modified.
+    pub fn append<const MAX2: usize>(
+        &mut self,
+        other: &mut Confined<BTreeSet<T>, ZERO, MAX2>,
+    ) -> Result<(), Error> {
+        let len = self.len() + other.iter().filter(|elem| !self.0.contains(elem)).count();
+        if len > MAX_LEN {
+            return Err(Error::Oversize {
+                len,
+                max_len: MAX_LEN,
+            });
+        }
+        self.0.append(&mut other.0);
+        Ok(())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -1398,6 +2109,22 @@ impl<K: Hash + Eq, V, const MIN_LEN: usize, const MAX_LEN: usize>
         Ok(self.0.remove(key))
     }
 
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.get(key)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    pub fn get_key_value<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.get_key_value(key)
+    }
+
     /// Creates a consuming iterator visiting all the keys in arbitrary order.
     /// The map cannot be used after calling this.
     /// The iterator element type is `K`.
@@ -1434,6 +2161,79 @@ impl<K: Ord + Hash, V, const MIN_LEN: usize, const MAX_LEN: usize>
         Ok(self.0.remove(key))
     }
 
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.get(key)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    pub fn get_key_value<Q: ?Sized + Ord>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.get_key_value(key)
+    }
+
+    /// Removes the first (minimal-key) entry from the map and returns it.
+    /// Returns `None` if the map is empty, and errors without modifying the
+    /// map if the new collection length will be less than the confinement
+    /// requirement.
+    pub fn pop_first(&mut self) -> Result<Option<(K, V)>, Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let len = self.len();
+        if len <= MIN_LEN {
+            return Err(Error::Undersize {
+                len,
+                min_len: MIN_LEN,
+            });
+        }
+        Ok(self.0.pop_first())
+    }
+
+    /// Removes the last (maximal-key) entry from the map and returns it.
+    /// Returns `None` if the map is empty, and errors without modifying the
+    /// map if the new collection length will be less than the confinement
+    /// requirement.
+    pub fn pop_last(&mut self) -> Result<Option<(K, V)>, Error> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let len = self.len();
+        if len <= MIN_LEN {
+            return Err(Error::Undersize {
+                len,
+                min_len: MIN_LEN,
+            });
+        }
+        Ok(self.0.pop_last())
+    }
+
+    /// Moves all the entries of `other` into `self`, leaving `other` empty.
+    /// If a key from `other` is already present in `self`, its value is
+    /// overwritten with the one from `other`.
+    ///
+    /// Only the number of distinct keys is checked against `MAX_LEN`. Errors if
+    /// it exceeds the limit; in this case neither of the maps is modified.
+    pub fn append<const MAX2: usize>(
+        &mut self,
+        other: &mut Confined<BTreeMap<K, V>, ZERO, MAX2>,
+    ) -> Result<(), Error> {
+        let len = self.len() + other.keys().filter(|key| !self.0.contains_key(key)).count();
+        if len > MAX_LEN {
+            return Err(Error::Oversize {
+                len,
+                max_len: MAX_LEN,
+            });
+        }
+        self.0.append(&mut other.0);
+        Ok(())
+    }
+
     /// Creates a consuming iterator visiting all the keys in arbitrary order.
     /// The map cannot be used after calling this.
     /// The iterator element type is `K`.
@@ -1447,6 +2247,28 @@ impl<K: Ord + Hash, V, const MIN_LEN: usize, const MAX_LEN: usize>
     pub fn into_values(self) -> btree_map::IntoValues<K, V> {
         self.0.into_values()
     }
+
+    /// Constructs a double-ended iterator over a sub-range of elements in the
+    /// map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end` or if range `start == end` and both
+    /// bounds are `Excluded`.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> btree_map::Range<'_, K, V> {
+        self.0.range(range)
+    }
+
+    /// Constructs a mutable double-ended iterator over a sub-range of elements
+    /// in the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end` or if range `start == end` and both
+    /// bounds are `Excluded`.
+    pub fn range_mut<R: RangeBounds<K>>(&mut self, range: R) -> btree_map::RangeMut<'_, K, V> {
+        self.0.range_mut(range)
+    }
 }
 
 // io::Writer
@@ -1468,6 +2290,100 @@ impl<const MAX_LEN: usize> io::Write for Confined<Vec<u8>, ZERO, MAX_LEN> {
 
 // Vec<u8>-specific things
 
+impl<const MIN_LEN: usize, const MAX_LEN: usize> Confined<Vec<u8>, MIN_LEN, MAX_LEN> {
+    /// Number of bytes in the length prefix used by
+    /// [`Self::to_len_prefixed_bytes`] and [`Self::from_len_prefixed_bytes`],
+    /// which is the minimal number of bytes able to hold `MAX_LEN`.
+    pub const LEN_PREFIX_SIZE: usize = if MAX_LEN <= U8 {
+        1
+    } else if MAX_LEN <= U16 {
+        2
+    } else if MAX_LEN <= U24 {
+        3
+    } else if MAX_LEN <= U32 {
+        4
+    } else {
+        8
+    };
+
+    /// Performs bitwise XOR of the blob with `other` in place.
+    ///
+    /// Errors with [`Error::OutOfBoundary`] if the lengths of the blob and
+    /// `other` differ; in this case the blob is not modified.
+    pub fn xor_with(&mut self, other: &[u8]) -> Result<(), Error> {
+        self.zip_with(other, |a, b| *a ^= b)
+    }
+
+    /// Performs bitwise AND of the blob with `other` in place.
+    ///
+    /// Errors with [`Error::OutOfBoundary`] if the lengths of the blob and
+    /// `other` differ; in this case the blob is not modified.
+    pub fn and_with(&mut self, other: &[u8]) -> Result<(), Error> {
+        self.zip_with(other, |a, b| *a &= b)
+    }
+
+    /// Performs bitwise OR of the blob with `other` in place.
+    ///
+    /// Errors with [`Error::OutOfBoundary`] if the lengths of the blob and
+    /// `other` differ; in this case the blob is not modified.
+    pub fn or_with(&mut self, other: &[u8]) -> Result<(), Error> {
+        self.zip_with(other, |a, b| *a |= b)
+    }
+
+    fn zip_with(&mut self, other: &[u8], f: impl Fn(&mut u8, u8)) -> Result<(), Error> {
+        let len = self.len().min(other.len());
+        if self.len() != other.len() {
+            return Err(Error::OutOfBoundary { index: len, len });
+        }
+        self.0.iter_mut().zip(other).for_each(|(a, b)| f(a, *b));
+        Ok(())
+    }
+
+    /// Returns the number of set bits across all bytes of the blob.
+    pub fn popcount(&self) -> u64 {
+        self.0.iter().map(|byte| byte.count_ones() as u64).sum()
+    }
+
+    /// Serializes the blob prefixed with its length, encoded as a
+    /// little-endian integer of [`Self::LEN_PREFIX_SIZE`] bytes.
+    pub fn to_len_prefixed_bytes(&self) -> Vec<u8> {
+        let prefix_len = Self::LEN_PREFIX_SIZE;
+        let mut vec = Vec::with_capacity(prefix_len + self.len());
+        vec.extend_from_slice(&(self.len() as u64).to_le_bytes()[..prefix_len]);
+        vec.extend_from_slice(&self.0);
+        vec
+    }
+
+    /// Deserializes the blob from the data produced by
+    /// [`Self::to_len_prefixed_bytes`], returning it together with the number
+    /// of bytes consumed. Any bytes after the blob data are ignored.
+    ///
+    /// Errors if the length prefix doesn't fit the confinement bounds, or with
+    /// [`Error::OutOfBoundary`] if the data are shorter than the length
+    /// prefix requires.
+    pub fn from_len_prefixed_bytes(bytes: &[u8]) -> Result<(Self, usize), Error> {
+        let prefix_len = Self::LEN_PREFIX_SIZE;
+        let prefix = bytes.get(..prefix_len).ok_or(Error::OutOfBoundary {
+            index: prefix_len - 1,
+            len: bytes.len(),
+        })?;
+        let mut buf = [0u8; 8];
+        buf[..prefix_len].copy_from_slice(prefix);
+        // A length not representable with `usize` can't fit the bounds anyway
+        let len = usize::try_from(u64::from_le_bytes(buf)).unwrap_or(usize::MAX);
+        Self::check_len(len)?;
+        let end = prefix_len.checked_add(len).ok_or(Error::OutOfBoundary {
+            index: usize::MAX,
+            len: bytes.len(),
+        })?;
+        let data = bytes.get(prefix_len..end).ok_or(Error::OutOfBoundary {
+            index: end - 1,
+            len: bytes.len(),
+        })?;
+        Ok((Self(data.to_vec()), end))
+    }
+}
+
 impl<const MIN_LEN: usize, const MAX_LEN: usize> LowerHex for Confined<Vec<u8>, MIN_LEN, MAX_LEN> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.write_str(&self.0.to_hex())
@@ -1808,6 +2724,68 @@ macro_rules! medium_vec {
     )
 }
 
+/// Helper macro to construct confined vector of a [`TinyVec`] type, returning
+/// [`Result`] instead of panicking if the number of items doesn't fit the
+/// confinement bounds.
+#[macro_export]
+macro_rules! try_tiny_vec {
+    () => {
+        Ok::<_, $crate::confinement::Error>($crate::confinement::TinyVec::new())
+    };
+    ($elem:expr; $n:expr) => (
+        $crate::confinement::TinyVec::try_from(vec![$elem; $n])
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::confinement::TinyVec::try_from(vec![$($x,)+])
+    )
+}
+
+/// Helper macro to construct confined vector of a [`SmallVec`] type, returning
+/// [`Result`] instead of panicking if the number of items doesn't fit the
+/// confinement bounds.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate amplify;
+/// # use amplify::confinement::{self, SmallVec};
+/// fn triple(a: u8, b: u8, c: u8) -> Result<SmallVec<u8>, confinement::Error> {
+///     let v = try_small_vec![a, b, c]?;
+///     Ok(v)
+/// }
+///
+/// assert_eq!(triple(1, 2, 3).unwrap(), small_vec![1, 2, 3]);
+/// assert!(try_tiny_vec![0u8; 256].is_err());
+/// ```
+#[macro_export]
+macro_rules! try_small_vec {
+    () => {
+        Ok::<_, $crate::confinement::Error>($crate::confinement::SmallVec::new())
+    };
+    ($elem:expr; $n:expr) => (
+        $crate::confinement::SmallVec::try_from(vec![$elem; $n])
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::confinement::SmallVec::try_from(vec![$($x,)+])
+    )
+}
+
+/// Helper macro to construct confined vector of a [`MediumVec`] type, returning
+/// [`Result`] instead of panicking if the number of items doesn't fit the
+/// confinement bounds.
+#[macro_export]
+macro_rules! try_medium_vec {
+    () => {
+        Ok::<_, $crate::confinement::Error>($crate::confinement::MediumVec::new())
+    };
+    ($elem:expr; $n:expr) => (
+        $crate::confinement::MediumVec::try_from(vec![$elem; $n])
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::confinement::MediumVec::try_from(vec![$($x,)+])
+    )
+}
+
 /// Helper macro to construct confined [`HashSet`] of a given type
 #[macro_export]
 #[deprecated(since = "4.7.0", note = "use size-specific macros")]
@@ -1909,6 +2887,9 @@ macro_rules! medium_bset {
 }
 
 /// Helper macro to construct confined [`HashMap`] of a given type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 #[deprecated(since = "4.7.0", note = "use size-specific macros")]
 macro_rules! confined_map {
@@ -1923,6 +2904,9 @@ macro_rules! confined_map {
 }
 
 /// Helper macro to construct confined [`HashMap`] of a [`TinyHashMap`] type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 macro_rules! tiny_map {
     () => {
@@ -1935,6 +2919,9 @@ macro_rules! tiny_map {
 }
 
 /// Helper macro to construct confined [`HashMap`] of a [`SmallHashMap`] type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 macro_rules! small_map {
     () => {
@@ -1947,6 +2934,9 @@ macro_rules! small_map {
 }
 
 /// Helper macro to construct confined [`HashMap`] of a [`MediumHashMap`] type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 macro_rules! medium_map {
     () => {
@@ -1959,6 +2949,9 @@ macro_rules! medium_map {
 }
 
 /// Helper macro to construct confined [`BTreeMap`] of a given type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 #[deprecated(since = "4.7.0", note = "use size-specific macros")]
 macro_rules! confined_bmap {
@@ -1973,6 +2966,9 @@ macro_rules! confined_bmap {
 }
 
 /// Helper macro to construct confined [`BTreeMap`] of a [`TinyOrdMap`] type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 macro_rules! tiny_bmap {
     () => {
@@ -1985,6 +2981,9 @@ macro_rules! tiny_bmap {
 }
 
 /// Helper macro to construct confined [`BTreeMap`] of a [`SmallOrdMap`] type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 macro_rules! small_bmap {
     () => {
@@ -1997,6 +2996,9 @@ macro_rules! small_bmap {
 }
 
 /// Helper macro to construct confined [`BTreeMap`] of a [`MediumOrdMap`] type
+///
+/// If the same key is given more than once, the last value wins; only
+/// distinct keys are counted against the confinement bounds.
 #[macro_export]
 macro_rules! medium_bmap {
     () => {
@@ -2127,4 +3129,674 @@ mod test {
         *coll.get_mut(&1).unwrap() = "five";
         assert_eq!(coll.get(&1), Some(&"five"));
     }
+
+    #[test]
+    fn modify() {
+        let mut vec = NonEmptyVec::<u8, 4>::with(1);
+        {
+            let mut guard = vec.modify();
+            guard.extend([2, 3, 4]);
+            guard.commit().unwrap();
+        }
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+
+        let mut guard = vec.modify();
+        guard.push(5);
+        assert_eq!(guard.commit(), Err(Error::Oversize { len: 5, max_len: 4 }));
+        guard.clear();
+        assert_eq!(guard.commit(), Err(Error::Undersize { len: 0, min_len: 1 }));
+        guard.push(6);
+        guard.commit().unwrap();
+        drop(guard);
+        assert_eq!(vec.as_slice(), &[6]);
+    }
+
+    #[test]
+    fn modify_uncommitted() {
+        let mut vec = TinyVec::<u8>::new();
+        vec.modify().extend([1u8; 255]);
+        assert!(vec.is_empty());
+
+        let mut guard = vec.modify();
+        guard.push(1);
+        guard.commit().unwrap();
+        guard.push(2);
+        drop(guard);
+        assert_eq!(vec.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn modify_forget() {
+        let mut vec = NonEmptyVec::<u8, 4>::with(1);
+        let mut guard = vec.modify();
+        guard.clear();
+        core::mem::forget(guard);
+        assert_eq!(vec.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn modify_unwind() {
+        let mut vec = NonEmptyVec::<u8, 4>::with(1);
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut guard = vec.modify();
+            guard.clear();
+            panic!("interrupted modification");
+        }));
+        assert!(res.is_err());
+        assert_eq!(vec.as_slice(), &[1]);
+
+        let mut vec = tiny_vec![1u8, 2, 3];
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut guard = vec.modify();
+            guard.push(4);
+            panic!("interrupted modification");
+        }));
+        assert!(res.is_err());
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn len_prefixed_bytes() {
+        fn check<const MAX_LEN: usize>(prefix_len: usize) {
+            let data = [0xA5u8; 300];
+            let len = data.len().min(MAX_LEN);
+            let blob = Confined::<Vec<u8>, ZERO, MAX_LEN>::try_from_slice(&data[..len]).unwrap();
+            let mut bytes = blob.to_len_prefixed_bytes();
+            assert_eq!(
+                Confined::<Vec<u8>, ZERO, MAX_LEN>::LEN_PREFIX_SIZE,
+                prefix_len
+            );
+            assert_eq!(bytes.len(), prefix_len + len);
+            assert_eq!(
+                bytes[..prefix_len],
+                (len as u64).to_le_bytes()[..prefix_len]
+            );
+            bytes.push(0xFF);
+            assert_eq!(
+                Confined::<Vec<u8>, ZERO, MAX_LEN>::from_len_prefixed_bytes(&bytes),
+                Ok((blob, prefix_len + len))
+            );
+        }
+        check::<U8>(1);
+        check::<U16>(2);
+        check::<U24>(3);
+        check::<U32>(4);
+        check::<U64>(8);
+
+        assert_eq!(
+            Confined::<Vec<u8>, ZERO, U64>::from_len_prefixed_bytes(&[0xFF; 10]),
+            Err(Error::OutOfBoundary {
+                index: usize::MAX,
+                len: 10
+            })
+        );
+        assert_eq!(
+            TinyBlob::from_len_prefixed_bytes(&[]),
+            Err(Error::OutOfBoundary { index: 0, len: 0 })
+        );
+        assert_eq!(
+            SmallBlob::from_len_prefixed_bytes(&[3, 0, 1, 2]),
+            Err(Error::OutOfBoundary { index: 4, len: 4 })
+        );
+        assert_eq!(
+            NonEmptyBlob::<U8>::from_len_prefixed_bytes(&[0]),
+            Err(Error::Undersize { len: 0, min_len: 1 })
+        );
+        assert_eq!(
+            Confined::<Vec<u8>, 0, 10>::from_len_prefixed_bytes(&[11]),
+            Err(Error::Oversize {
+                len: 11,
+                max_len: 10
+            })
+        );
+    }
+
+    #[test]
+    fn vec_search() {
+        let vec = small_vec!(3u16, 8, 15, 42);
+        assert_eq!(vec.position(|x| *x > 10), Some(2));
+        assert_eq!(vec.position(|x| *x > 100), None);
+        assert_eq!(vec.find(|x| x % 2 == 0), Some(&8));
+        assert_eq!(vec.find(|x| *x == 0), None);
+    }
+
+    #[test]
+    fn str_predicates() {
+        let s = small_s!("  confined string\t");
+        assert_eq!(s.trim(), "confined string");
+        assert_eq!(s.trim_start(), "confined string\t");
+        assert_eq!(s.trim_end(), "  confined string");
+        assert!(s.contains("fined"));
+        assert!(!s.contains("unconfined"));
+        assert!(s.starts_with("  conf"));
+        assert!(s.ends_with("string\t"));
+
+        let a = SmallAscii::try_from(AsciiString::from_str(" ascii ").unwrap()).unwrap();
+        assert_eq!(a.trim().as_str(), "ascii");
+        assert!(a.contains("sci"));
+    }
+
+    #[test]
+    fn from_truncated() {
+        let vec = TinyVec::from_truncated(vec![7u8; 300]).unwrap();
+        assert_eq!(vec.len(), 255);
+        assert!(vec.iter().all(|x| *x == 7));
+
+        let vec = TinyVec::from_truncated(vec![1u8, 2, 3]).unwrap();
+        assert_eq!(vec, tiny_vec![1, 2, 3]);
+
+        assert_eq!(
+            NonEmptyVec::<u8, 3>::from_truncated(vec![]),
+            Err(Error::Undersize { len: 0, min_len: 1 })
+        );
+
+        let s = Confined::<String, 0, 4>::from_truncated(s!("abcdef")).unwrap();
+        assert_eq!(s.as_str(), "abcd");
+        let s = Confined::<String, 0, 4>::from_truncated(s!("abcé")).unwrap();
+        assert_eq!(s.as_str(), "abc");
+    }
+
+    #[test]
+    fn map() {
+        let vec = NonEmptyVec::<u8, 4>::try_from(vec![1, 2, 3]).unwrap();
+        let mapped: NonEmptyVec<String, 4> = vec.clone().map(|x| x.to_string());
+        assert_eq!(mapped.as_slice(), &["1", "2", "3"]);
+
+        let mut calls = 0;
+        let res = vec.clone().try_map(|x| {
+            calls += 1;
+            if x == 2 {
+                Err(x)
+            } else {
+                Ok(x as u16)
+            }
+        });
+        assert_eq!(res, Err(2));
+        assert_eq!(calls, 2);
+
+        let mapped: NonEmptyVec<u16, 4> = vec.try_map(|x| Ok::<_, ()>(x as u16 * 100)).unwrap();
+        assert_eq!(mapped.as_slice(), &[100, 200, 300]);
+    }
+
+    #[test]
+    fn popcount() {
+        assert_eq!(TinyBlob::new().popcount(), 0);
+        let blob = TinyBlob::try_from(vec![0xFF, 0x00, 0x0F, 0xAA, 0x01]).unwrap();
+        assert_eq!(blob.popcount(), 17);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut vec = tiny_vec![1u8, 2, 3, 4];
+        let tail = vec.split_off(4).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(vec, tiny_vec![1, 2, 3, 4]);
+
+        let tail = vec.split_off(0).unwrap();
+        assert_eq!(tail, tiny_vec![1, 2, 3, 4]);
+        assert!(vec.is_empty());
+
+        let mut vec = Confined::<Vec<u8>, 2, 10>::try_from(vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(
+            vec.split_off(5),
+            Err(Error::OutOfBoundary { index: 5, len: 4 })
+        );
+        assert_eq!(
+            vec.split_off(1),
+            Err(Error::Undersize { len: 1, min_len: 2 })
+        );
+        assert_eq!(vec.len(), 4);
+        let tail: Confined<Vec<u8>, 0, 10> = vec.split_off(2).unwrap();
+        assert_eq!(tail.as_slice(), &[3, 4]);
+        assert_eq!(vec.as_slice(), &[1, 2]);
+    }
+
+    #[test]
+    fn map_macros_duplicate_keys() {
+        let map = tiny_map! { 1 => 'a', 1 => 'b' };
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&1), Some(&'b'));
+
+        let map = tiny_bmap! { 1 => 'a', 2 => 'b', 1 => 'c', 2 => 'd' };
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&1), Some(&'c'));
+        assert_eq!(map.get(&2), Some(&'d'));
+
+        // Four entries with two distinct keys fit a map limited to two items
+        let map = Confined::<BTreeMap<u8, char>, 0, 2>::try_from(
+            bmap! { 1 => 'a', 2 => 'b', 1 => 'c', 2 => 'd' },
+        )
+        .unwrap();
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn bmap_range() {
+        let mut map = SmallOrdMap::try_from_iter((1u8..100).map(|i| (i, i as u16))).unwrap();
+        assert_eq!(map.range(10..20).count(), 10);
+        assert_eq!(map.range(10..20).next(), Some((&10, &10)));
+        assert_eq!(map.range(95..).count(), 5);
+
+        for (_, value) in map.range_mut(..=3) {
+            *value *= 100;
+        }
+        assert_eq!(map.get(&3), Some(&300));
+        assert_eq!(map.get(&4), Some(&4));
+        assert_eq!(map.len(), 99);
+    }
+
+    #[test]
+    fn pop_first_last() {
+        let mut set = NonEmptyOrdSet::<u8, 8>::try_from_iter([3, 1, 2]).unwrap();
+        assert_eq!(set.pop_first(), Ok(Some(1)));
+        assert_eq!(set.pop_last(), Ok(Some(3)));
+        assert_eq!(
+            set.pop_first(),
+            Err(Error::Undersize { len: 1, min_len: 1 })
+        );
+        assert_eq!(set.pop_last(), Err(Error::Undersize { len: 1, min_len: 1 }));
+        assert_eq!(set.len(), 1);
+
+        let mut set = TinyOrdSet::<u8>::new();
+        assert_eq!(set.pop_first(), Ok(None));
+        assert_eq!(set.pop_last(), Ok(None));
+
+        let mut map = tiny_bmap! { 1u8 => 'a', 2 => 'b', 3 => 'c' };
+        assert_eq!(map.pop_first(), Ok(Some((1, 'a'))));
+        assert_eq!(map.pop_last(), Ok(Some((3, 'c'))));
+        assert_eq!(map.pop_last(), Ok(Some((2, 'b'))));
+        assert_eq!(map.pop_last(), Ok(None));
+
+        let mut map = NonEmptyOrdMap::<u8, char, 8>::with_key_value(1, 'a');
+        assert_eq!(
+            map.pop_first(),
+            Err(Error::Undersize { len: 1, min_len: 1 })
+        );
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn append() {
+        let mut vec = Confined::<Vec<u8>, 1, 4>::try_from(vec![1, 2]).unwrap();
+        let mut other = tiny_vec![3u8, 4, 5];
+        assert_eq!(
+            vec.append(&mut other),
+            Err(Error::Oversize { len: 5, max_len: 4 })
+        );
+        assert_eq!(vec.as_slice(), &[1, 2]);
+        assert_eq!(other.len(), 3);
+        other.pop();
+        vec.append(&mut other).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+        assert!(other.is_empty());
+
+        let mut set = Confined::<BTreeSet<u8>, 0, 4>::try_from_iter([1, 2, 3]).unwrap();
+        let mut other = TinyOrdSet::try_from_iter([2, 3, 4]).unwrap();
+        set.append(&mut other).unwrap();
+        assert_eq!(set.len(), 4);
+        assert!(other.is_empty());
+        let mut other = TinyOrdSet::try_from_iter([4, 5]).unwrap();
+        assert_eq!(
+            set.append(&mut other),
+            Err(Error::Oversize { len: 5, max_len: 4 })
+        );
+        assert_eq!(other.len(), 2);
+
+        let mut map = Confined::<BTreeMap<u8, char>, 0, 2>::try_from(bmap! { 1 => 'a' }).unwrap();
+        let mut other = tiny_bmap! { 1 => 'b', 2 => 'c' };
+        map.append(&mut other).unwrap();
+        assert_eq!(map.get(&1), Some(&'b'));
+        assert_eq!(map.len(), 2);
+        let mut other = tiny_bmap! { 3 => 'd' };
+        assert_eq!(
+            map.append(&mut other),
+            Err(Error::Oversize { len: 3, max_len: 2 })
+        );
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn bitwise_with() {
+        let mut blob = TinyBlob::try_from(vec![0b1100, 0xFF, 0x00]).unwrap();
+        blob.xor_with(&[0b1010, 0x0F, 0xF0]).unwrap();
+        assert_eq!(blob.as_slice(), &[0b0110, 0xF0, 0xF0]);
+        blob.and_with(&[0b0100, 0xFF, 0x30]).unwrap();
+        assert_eq!(blob.as_slice(), &[0b0100, 0xF0, 0x30]);
+        blob.or_with(&[0b0001, 0x0F, 0x00]).unwrap();
+        assert_eq!(blob.as_slice(), &[0b0101, 0xFF, 0x30]);
+
+        assert_eq!(
+            blob.xor_with(&[1, 2]),
+            Err(Error::OutOfBoundary { index: 2, len: 2 })
+        );
+        assert_eq!(
+            blob.or_with(&[1, 2, 3, 4]),
+            Err(Error::OutOfBoundary { index: 3, len: 3 })
+        );
+        assert_eq!(blob.as_slice(), &[0b0101, 0xFF, 0x30]);
+    }
+
+    #[test]
+    fn resize() {
+        let mut vec = Confined::<Vec<u8>, 2, 5>::try_from(vec![1, 2, 3]).unwrap();
+        vec.resize(3, 0).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+        vec.resize(5, 0).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 0, 0]);
+        assert_eq!(
+            vec.resize(6, 0),
+            Err(Error::Oversize { len: 6, max_len: 5 })
+        );
+        assert_eq!(
+            vec.resize(1, 0),
+            Err(Error::Undersize { len: 1, min_len: 2 })
+        );
+        assert_eq!(vec.len(), 5);
+        vec.resize(2, 0).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2]);
+
+        let mut next = 10;
+        vec.resize_with(4, || {
+            next += 1;
+            next
+        })
+        .unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 11, 12]);
+        assert_eq!(
+            vec.resize_with(0, || 0),
+            Err(Error::Undersize { len: 0, min_len: 2 })
+        );
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut blob = LargeBlob::with_capacity(1000);
+        blob.extend_from_slice(&[0xA5; 1000]).unwrap();
+        blob.extend_from_slice(&[]).unwrap();
+        assert_eq!(blob.len(), 1000);
+        assert!(blob.iter().all(|b| *b == 0xA5));
+
+        let mut blob = TinyBlob::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(
+            blob.extend_from_slice(&[0; 253]),
+            Err(Error::Oversize {
+                len: 256,
+                max_len: 255
+            })
+        );
+        assert_eq!(blob.as_slice(), &[1, 2, 3]);
+        blob.extend_from_slice(&[0; 252]).unwrap();
+        assert_eq!(blob.len(), 255);
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut vec = NonEmptyVec::<char, 8>::try_from(vec!['a', 'b', 'c', 'd']).unwrap();
+        assert_eq!(
+            vec.swap_remove(4),
+            Err(Error::OutOfBoundary { index: 4, len: 4 })
+        );
+        assert_eq!(vec.swap_remove(1), Ok('b'));
+        assert_eq!(vec.as_slice(), &['a', 'd', 'c']);
+        assert_eq!(vec.swap_remove(2), Ok('c'));
+        assert_eq!(vec.swap_remove(0), Ok('a'));
+        assert_eq!(
+            vec.swap_remove(0),
+            Err(Error::Undersize { len: 1, min_len: 1 })
+        );
+        assert_eq!(vec.as_slice(), &['d']);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut vec = tiny_vec![1u8, 1, 2, 3, 3, 3, 1];
+        vec.dedup().unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 1]);
+
+        let mut vec = Confined::<Vec<u8>, 3, 8>::try_from(vec![1, 1, 2, 2, 2]).unwrap();
+        assert_eq!(vec.dedup(), Err(Error::Undersize { len: 2, min_len: 3 }));
+        assert_eq!(vec.as_slice(), &[1, 1, 2, 2, 2]);
+
+        let mut calls = 0;
+        let mut vec = tiny_vec![10u8, 11, 20, 25, 31];
+        vec.dedup_by_key(|x| {
+            calls += 1;
+            *x / 10
+        })
+        .unwrap();
+        assert_eq!(calls, 5);
+        assert_eq!(vec.as_slice(), &[10, 20, 31]);
+
+        let mut vec = Confined::<Vec<u8>, 3, 8>::try_from(vec![10, 11, 20, 25]).unwrap();
+        assert_eq!(
+            vec.dedup_by_key(|x| *x / 10),
+            Err(Error::Undersize { len: 2, min_len: 3 })
+        );
+        assert_eq!(vec.as_slice(), &[10, 11, 20, 25]);
+    }
+
+    #[test]
+    fn from_array() {
+        let vec = TinyVec::from([1u8, 2, 3]);
+        assert_eq!(vec, tiny_vec![1, 2, 3]);
+        let vec = Confined::<Vec<u8>, 2, 2>::from([1, 2]);
+        assert_eq!(vec.as_slice(), &[1, 2]);
+        let vec = TinyVec::<u8>::from([]);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn insert() {
+        let mut vec = Confined::<Vec<u8>, 0, 4>::try_from(vec![2]).unwrap();
+        vec.insert(0, 1).unwrap();
+        vec.insert(2, 4).unwrap();
+        vec.insert(2, 3).unwrap();
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(
+            vec.insert(0, 0),
+            Err(Error::Oversize { len: 5, max_len: 4 })
+        );
+        vec.pop();
+        assert_eq!(
+            vec.insert(4, 0),
+            Err(Error::OutOfBoundary { index: 4, len: 3 })
+        );
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+        let mut s = Confined::<String, 0, 8>::try_from(s!("bé")).unwrap();
+        s.insert(0, 'a').unwrap();
+        s.insert_str(s.len(), "yz").unwrap();
+        s.insert(2, '-').unwrap();
+        assert_eq!(s.as_str(), "ab-éyz");
+        assert_eq!(s.insert(4, 'x'), Err(StrInsertError::NotCharBoundary(4)));
+        assert_eq!(
+            s.insert_str(8, "x"),
+            Err(StrInsertError::Confinement(Error::OutOfBoundary {
+                index: 8,
+                len: 7
+            }))
+        );
+        assert_eq!(
+            s.insert_str(0, "xy"),
+            Err(StrInsertError::Confinement(Error::Oversize {
+                len: 9,
+                max_len: 8
+            }))
+        );
+        assert_eq!(s.as_str(), "ab-éyz");
+    }
+
+    #[test]
+    fn utf8_bytes() {
+        // "naïve" has five characters, but six bytes
+        let bytes = "naïve".as_bytes().to_vec();
+        assert_eq!(
+            Confined::<String, 0, 5>::from_utf8(bytes.clone()),
+            Err(Utf8Error::Confinement(Error::Oversize {
+                len: 6,
+                max_len: 5
+            }))
+        );
+        let s = Confined::<String, 6, 6>::from_utf8(bytes.clone()).unwrap();
+        assert_eq!(s.as_str(), "naïve");
+        let blob: Confined<Vec<u8>, 6, 6> = s.into_bytes();
+        assert_eq!(blob.release(), bytes);
+
+        assert!(matches!(
+            Confined::<String, 0, 8>::from_utf8(vec![0x61, 0xFF]),
+            Err(Utf8Error::Utf8(_))
+        ));
+
+        let s = Confined::<String, 0, 4>::from_utf8_lossy(&[0x61, 0xFF]).unwrap();
+        assert_eq!(s.as_str(), "a\u{FFFD}");
+        assert_eq!(
+            Confined::<String, 0, 4>::from_utf8_lossy(&[0x61, 0x62, 0xFF]),
+            Err(Error::Oversize { len: 5, max_len: 4 })
+        );
+    }
+
+    #[test]
+    fn map_get() {
+        let map = tiny_map! { s!("one") => 1u8, s!("two") => 2 };
+        assert_eq!(map.get("one"), Some(&1));
+        assert_eq!(map.get("three"), None);
+        assert_eq!(map.get_key_value("two"), Some((&s!("two"), &2)));
+        assert_eq!(map.get_key_value("four"), None);
+
+        let map = tiny_bmap! { 1u8 => 'a', 2 => 'b' };
+        assert_eq!(map.get(&1), Some(&'a'));
+        assert_eq!(map.get(&3), None);
+        assert_eq!(map.get_key_value(&2), Some((&2, &'b')));
+        assert_eq!(map.get_key_value(&0), None);
+    }
+
+    #[test]
+    fn vec_accessors() {
+        let mut vec = tiny_vec![1u8, 2, 3];
+        assert_eq!(vec.first(), Some(&1));
+        assert_eq!(vec.last(), Some(&3));
+        assert_eq!(vec.get(1), Some(&2));
+        assert_eq!(vec.get(3), None);
+        assert_eq!(vec.get(1..), Some(&[2u8, 3][..]));
+        vec.clear();
+        assert_eq!(vec.first(), None);
+        assert_eq!(vec.last(), None);
+
+        let mut vec = NonEmptyVec::<u8, 4>::with(7);
+        assert_eq!(vec.first_item(), &7);
+        assert_eq!(vec.last_item(), &7);
+        vec.push(8).unwrap();
+        assert_eq!(vec.first_item(), &7);
+        assert_eq!(vec.last_item(), &8);
+        assert_eq!(vec.first(), Some(&7));
+        assert_eq!(vec.last(), Some(&8));
+        assert_eq!(vec.get(1), Some(&8));
+
+        let vec = Confined::<Vec<u8>, 2, 10>::try_from(vec![3, 4, 5]).unwrap();
+        assert_eq!(vec.first_item(), &3);
+        assert_eq!(vec.last_item(), &5);
+    }
+
+    #[test]
+    fn narrow_widen() {
+        let vec = SmallVec::try_from_iter(0u8..200).unwrap();
+        let tiny: TinyVec<u8> = vec.narrow().unwrap();
+        assert_eq!(tiny.len(), 200);
+
+        let vec = SmallVec::try_from_iter(0..300u16).unwrap();
+        assert_eq!(
+            vec.clone().narrow::<0, U8>(),
+            Err(Error::Oversize {
+                len: 300,
+                max_len: 255
+            })
+        );
+        assert_eq!(
+            vec.narrow::<301, U16>(),
+            Err(Error::Undersize {
+                len: 300,
+                min_len: 301
+            })
+        );
+
+        let small: SmallVec<u8> = tiny.widen();
+        assert_eq!(small.len(), 200);
+    }
+
+    #[test]
+    fn try_collect() {
+        let vec = small_vec![1u8, 2, 3, 4];
+        let wide: SmallVec<u16> = vec
+            .clone()
+            .try_collect(|iter| iter.map(|x| x as u16 * 1000))
+            .unwrap();
+        assert_eq!(wide.as_slice(), &[1000, 2000, 3000, 4000]);
+
+        let even: SmallVec<u16> = vec
+            .try_collect(|iter| iter.filter(|x| x % 2 == 0).map(u16::from))
+            .unwrap();
+        assert_eq!(even.as_slice(), &[2, 4]);
+
+        let vec = NonEmptyVec::<u8, 4>::try_from(vec![1, 3]).unwrap();
+        assert_eq!(
+            vec.clone().try_collect(|iter| iter.filter(|x| x % 2 == 0)),
+            Err(Error::Undersize { len: 0, min_len: 1 })
+        );
+        assert_eq!(
+            vec.try_collect(|iter| iter.flat_map(|x| [x; 3])),
+            Err(Error::Oversize { len: 6, max_len: 4 })
+        );
+    }
+
+    #[test]
+    fn chunks_windows() {
+        let blob = SmallBlob::try_from_iter(0u8..10).unwrap();
+        let chunks = blob.chunks(4).collect::<Vec<_>>();
+        assert_eq!(chunks, vec![&[0, 1, 2, 3][..], &[4, 5, 6, 7], &[8, 9]]);
+        assert_eq!(blob.windows(9).count(), 2);
+        assert_eq!(blob.windows(3).last(), Some(&[7u8, 8, 9][..]));
+        assert_eq!(blob.windows(11).next(), None);
+    }
+
+    #[test]
+    fn try_extend_exact() {
+        let mut vec = SmallVec::<u16>::new();
+        vec.try_extend_exact(0..10_000).unwrap();
+        assert_eq!(vec.len(), 10_000);
+        assert_eq!(vec.last(), Some(&9_999));
+        assert_eq!(
+            vec.try_extend_exact(0..60_000),
+            Err(Error::Oversize {
+                len: 70_000,
+                max_len: U16
+            })
+        );
+        assert_eq!(vec.len(), 10_000);
+
+        let mut deque = Confined::<VecDeque<u8>, 0, 4>::with_capacity(4);
+        deque.try_extend_exact([1, 2, 3]).unwrap();
+        assert_eq!(
+            deque.try_extend_exact([4, 5]),
+            Err(Error::Oversize { len: 5, max_len: 4 })
+        );
+        deque.try_extend_exact(vec![4]).unwrap();
+        assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn try_vec_macros() {
+        assert_eq!(try_tiny_vec![1u8, 2, 3], Ok(tiny_vec![1, 2, 3]));
+        assert_eq!(
+            try_tiny_vec![0u8; 256],
+            Err(Error::Oversize {
+                len: 256,
+                max_len: 255
+            })
+        );
+        assert_eq!(try_small_vec![7u16; 3].unwrap().len(), 3);
+        let empty: MediumVec<u8> = try_medium_vec!().unwrap();
+        assert!(empty.is_empty());
+        let empty: Result<TinyVec<u8>, _> = try_tiny_vec!();
+        assert_eq!(empty, Ok(TinyVec::new()));
+    }
 }
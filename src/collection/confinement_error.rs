@@ -0,0 +1,113 @@
+// Rust language amplification library providing multiple generic trait
+// implementations, type wrappers, derive macros and other language enhancements
+//
+// Written in 2022-2024 by
+//     Dr. Maxim Orlovsky <orlovsky@ubideco.org>
+//
+// To the extent possible under law, the author(s) have dedicated all
+// copyright and related and neighboring rights to this software to
+// the public domain worldwide. This software is distributed without
+// any warranty.
+//
+// You should have received a copy of the MIT License
+// along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Errors of confined collections, which are available without `alloc`.
+
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::io;
+
+/// Errors when confinement constraints were not met.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Error {
+    /// Operation results in collection reduced below the required minimum
+    /// number of elements.
+    Undersize {
+        /** Current collection length */
+        len: usize,
+        /** Minimum number of elements which must be present in the
+         * collection */
+        min_len: usize,
+    },
+
+    /// Operation results in collection growth above the required maximum number
+    /// of elements.
+    Oversize {
+        /** Current collection length */
+        len: usize,
+        /** Maximum number of elements which must be present in the
+         * collection */
+        max_len: usize,
+    },
+
+    /// Attempt to address an index outside the collection bounds.
+    OutOfBoundary {
+        /** Index which was outside the bounds */
+        index: usize,
+        /** The actual number of elements in the collection */
+        len: usize,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Undersize { len, min_len } => write!(
+                f,
+                "operation results in collection size {len} less than lower boundary \
+                 of {min_len}, which is prohibited"
+            ),
+            Error::Oversize { len, max_len } => write!(
+                f,
+                "operation results in collection size {len} exceeding {max_len}, \
+                which is prohibited"
+            ),
+            Error::OutOfBoundary { index, len } => write!(
+                f,
+                "attempt to access the element at {index} which is outside of the \
+                collection length boundary {len}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for io::Error {
+    /// Converts confinement error into I/O error of [`io::ErrorKind::OutOfMemory`]
+    /// kind for [`Error::Oversize`], [`io::ErrorKind::InvalidData`] for
+    /// [`Error::Undersize`] and [`io::ErrorKind::InvalidInput`] for
+    /// [`Error::OutOfBoundary`].
+    fn from(err: Error) -> Self {
+        let kind = match err {
+            Error::Undersize { .. } => io::ErrorKind::InvalidData,
+            Error::Oversize { .. } => io::ErrorKind::OutOfMemory,
+            Error::OutOfBoundary { .. } => io::ErrorKind::InvalidInput,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+/// Checks whether a collection of a given length fits the `MIN_LEN..=MAX_LEN`
+/// confinement bounds.
+pub(crate) fn check_len<const MIN_LEN: usize, const MAX_LEN: usize>(
+    len: usize,
+) -> Result<(), Error> {
+    if len < MIN_LEN {
+        return Err(Error::Undersize {
+            len,
+            min_len: MIN_LEN,
+        });
+    }
+    if len > MAX_LEN {
+        return Err(Error::Oversize {
+            len,
+            max_len: MAX_LEN,
+        });
+    }
+    Ok(())
+}
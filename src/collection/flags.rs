@@ -343,13 +343,13 @@ impl FlagVec {
 
     /// Creates an iterator for the current feature flags which have "set" state
     #[inline]
-    pub fn iter(&self) -> AllSet {
+    pub fn iter(&self) -> AllSet<'_> {
         AllSet::new(self)
     }
 
     /// Creates iterator over known set of the features
     #[inline]
-    pub fn known_iter(&self, mut known: FlagVec) -> FilteredIter {
+    pub fn known_iter(&self, mut known: FlagVec) -> FilteredIter<'_> {
         known.enlarge(self.capacity());
         FilteredIter::new(self, known)
     }
@@ -357,7 +357,7 @@ impl FlagVec {
     /// Creates iterator over unknown set of the features, i.e. features that
     /// **do not** match flags set in `known` parameter
     #[inline]
-    pub fn unknown_iter(&self, mut known: FlagVec) -> FilteredIter {
+    pub fn unknown_iter(&self, mut known: FlagVec) -> FilteredIter<'_> {
         known.enlarge(self.capacity());
         for byte in 0..self.0.len() {
             known.0[byte] = !known.0[byte];
@@ -554,6 +554,86 @@ impl Iterator for FilteredIter<'_> {
     }
 }
 
+#[cfg(feature = "serde")]
+pub(crate) mod serde_helpers {
+    //! Serde serialization helpers
+
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{FlagNo, FlagVec};
+    use crate::confinement::TinyVec;
+
+    /// Maximal number of flags which may be kept by a [`FlagVec`].
+    const MAX_CAPACITY: FlagNo = 255 * 8;
+
+    /// Human-readable representation of a [`FlagVec`]: the list of set flags
+    /// together with the flag vector capacity, which is required to
+    /// reconstruct the exact same vector.
+    #[derive(Serialize, Deserialize)]
+    #[serde(crate = "serde_crate")]
+    struct FlagList {
+        capacity: FlagNo,
+        flags: Vec<FlagNo>,
+    }
+
+    impl Serialize for FlagVec {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if serializer.is_human_readable() {
+                FlagList {
+                    capacity: self.capacity(),
+                    flags: self.iter().collect(),
+                }
+                .serialize(serializer)
+            } else {
+                (self.capacity(), self.as_slice()).serialize(serializer)
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for FlagVec {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                let FlagList { capacity, flags } = FlagList::deserialize(deserializer)?;
+                if capacity % 8 != 0 || capacity > MAX_CAPACITY {
+                    return Err(D::Error::custom(format!(
+                        "invalid flag vector capacity {capacity}"
+                    )));
+                }
+                let bytes = TinyVec::try_from(vec![0u8; capacity as usize / 8])
+                    .expect("capacity is checked above");
+                let mut vec = FlagVec(bytes);
+                for flag in flags {
+                    if flag >= capacity {
+                        return Err(D::Error::custom(format!(
+                            "flag {flag} exceeds flag vector capacity {capacity}"
+                        )));
+                    }
+                    vec.set(flag);
+                }
+                Ok(vec)
+            } else {
+                let (capacity, bytes) = <(FlagNo, Vec<u8>)>::deserialize(deserializer)?;
+                if bytes.len() * 8 != capacity as usize {
+                    return Err(D::Error::custom(format!(
+                        "flag vector capacity {capacity} doesn't match the number of bytes {}",
+                        bytes.len()
+                    )));
+                }
+                TinyVec::try_from(bytes)
+                    .map(FlagVec)
+                    .map_err(|_| D::Error::custom("flag vector exceeds 255 bytes"))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -702,6 +782,40 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let mut f = FlagVec::from_str("---+--+--+++-").unwrap();
+        f.enlarge(30);
+
+        let json = serde_json::to_string(&f).unwrap();
+        assert_eq!(json, r#"{"capacity":32,"flags":[3,6,9,10,11]}"#);
+        let g = serde_json::from_str::<FlagVec>(&json).unwrap();
+        assert_eq!(g, f);
+        assert_eq!(g.capacity(), f.capacity());
+        assert_eq!(g.as_slice(), f.as_slice());
+
+        let bin = bincode::serialize(&f).unwrap();
+        let g = bincode::deserialize::<FlagVec>(&bin).unwrap();
+        assert_eq!(g, f);
+        assert_eq!(g.capacity(), f.capacity());
+        assert_eq!(g.as_slice(), f.as_slice());
+
+        let empty = FlagVec::new();
+        assert_eq!(
+            serde_json::to_string(&empty).unwrap(),
+            r#"{"capacity":0,"flags":[]}"#
+        );
+        let bin = bincode::serialize(&empty).unwrap();
+        assert_eq!(bincode::deserialize::<FlagVec>(&bin).unwrap(), empty);
+
+        assert!(serde_json::from_str::<FlagVec>(r#"{"capacity":8,"flags":[8]}"#).is_err());
+        assert!(serde_json::from_str::<FlagVec>(r#"{"capacity":12,"flags":[]}"#).is_err());
+        assert!(serde_json::from_str::<FlagVec>(r#"{"capacity":2048,"flags":[]}"#).is_err());
+        let full = serde_json::from_str::<FlagVec>(r#"{"capacity":2040,"flags":[2039]}"#).unwrap();
+        assert!(full.is_set(2039));
+    }
+
     #[test]
     fn test_zero_pos() {
         let mut f: FlagVec = none!();
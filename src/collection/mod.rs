@@ -17,6 +17,8 @@
 #[macro_use]
 pub mod confinement;
 mod array;
+mod confined_slice;
+mod confinement_error;
 #[cfg(feature = "std")]
 pub mod flags;
 
@@ -25,5 +27,7 @@ pub use array::{
     Array, Bytes, Bytes4, Bytes16, Bytes20, Bytes32, Bytes32StrRev, Bytes64, ByteArray, RawArray,
     FromSliceError,
 };
+pub use confined_slice::ConfinedSlice;
+pub use confinement_error::Error as ConfinementError;
 #[cfg(feature = "std")]
 pub use flags::{FlagRef, FlagNo, FlagVec};
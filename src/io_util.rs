@@ -20,6 +20,8 @@ use std::fmt::{Debug, Display, Formatter, self};
 use std::error::Error as StdError;
 use std::hash::{Hash, Hasher};
 
+use crate::confinement;
+
 /// A simple way to count bytes written through [`io::Write`].
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default, Debug)]
 pub struct WriteCounter {
@@ -135,6 +137,12 @@ impl From<io::Error> for IoError {
     }
 }
 
+impl From<confinement::Error> for IoError {
+    fn from(err: confinement::Error) -> Self {
+        io::Error::from(err).into()
+    }
+}
+
 impl From<io::ErrorKind> for IoError {
     fn from(kind: io::ErrorKind) -> Self {
         IoError {
@@ -155,8 +163,32 @@ impl From<IoError> for io::Error {
     }
 }
 
-/// Errors with [`io::ErrorKind::UnexpectedEof`] on [`io::Read`] and
-/// [`io::Write`] operations if the `LIM` is reached.
+/// Errors with [`io::ErrorKind::UnexpectedEof`] on [`io::Read`] operations
+/// and with [`io::ErrorKind::WriteZero`] on [`io::Write`] operations if the
+/// `LIM` is reached.
+///
+/// When used as a writer, allows writing up to `LIM` bytes in total; a write
+/// which would exceed the limit fails without passing any data to the inner
+/// writer. The number of bytes written so far is reported by
+/// [`ConfinedIo::pos`].
+///
+/// # Example
+///
+/// ```
+/// use std::io::{self, Write};
+///
+/// use amplify::ConfinedIo;
+///
+/// let mut writer = ConfinedIo::<_, 4>::from(Vec::<u8>::new());
+/// writer.write_all(b"abc").unwrap();
+/// writer.write_all(b"d").unwrap();
+/// assert_eq!(writer.pos(), 4);
+/// assert_eq!(
+///     writer.write_all(b"e").unwrap_err().kind(),
+///     io::ErrorKind::WriteZero
+/// );
+/// assert_eq!(writer.into_io(), b"abcd");
+/// ```
 #[derive(Clone, Debug)]
 pub struct ConfinedIo<Io, const LIM: usize> {
     pos: usize,
@@ -218,12 +250,12 @@ impl<Io, const LIM: usize> ConfinedIo<Io, LIM> {
 
 impl<Io: io::Write, const LIM: usize> io::Write for ConfinedIo<Io, LIM> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let len = buf.len();
-        if self.pos + len >= LIM {
-            return Err(io::ErrorKind::UnexpectedEof.into());
+        if buf.len() > LIM.saturating_sub(self.pos) {
+            return Err(io::ErrorKind::WriteZero.into());
         }
-        self.pos += len;
-        self.io.write(buf)
+        let written = self.io.write(buf)?;
+        self.pos += written;
+        Ok(written)
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -238,7 +270,7 @@ impl<Io: io::Read, const LIM: usize> io::Read for ConfinedIo<Io, LIM> {
             self.pos += len;
             self.io.read(buf)
         } else if self.pos >= LIM {
-            return Err(io::ErrorKind::UnexpectedEof.into());
+            Err(io::ErrorKind::UnexpectedEof.into())
         } else {
             let pos = self.pos;
             self.pos = LIM;
@@ -252,7 +284,7 @@ impl<Io: io::Read, const LIM: usize> io::Read for ConfinedIo<Io, LIM> {
             self.pos += len;
             self.io.read_exact(buf)
         } else if self.pos >= LIM {
-            return Err(io::ErrorKind::UnexpectedEof.into());
+            Err(io::ErrorKind::UnexpectedEof.into())
         } else {
             let pos = self.pos;
             self.pos = LIM;
@@ -260,3 +292,54 @@ impl<Io: io::Read, const LIM: usize> io::Read for ConfinedIo<Io, LIM> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn confinement_error() {
+        let err = confinement::Error::Oversize {
+            len: 256,
+            max_len: 255,
+        };
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::OutOfMemory);
+        assert_eq!(IoError::from(err).kind(), io::ErrorKind::OutOfMemory);
+
+        let err = confinement::Error::Undersize { len: 0, min_len: 1 };
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+        assert_eq!(IoError::from(err).kind(), io::ErrorKind::InvalidData);
+
+        let err = confinement::Error::OutOfBoundary { index: 5, len: 2 };
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(IoError::from(err).kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn confined_write() {
+        let mut under = ConfinedIo::<_, 8>::from(Vec::<u8>::new());
+        under.write_all(&[1u8; 7]).unwrap();
+        assert_eq!(under.pos(), 7);
+        assert!(!under.is_eof());
+
+        let mut exact = ConfinedIo::<_, 8>::from(Vec::<u8>::new());
+        exact.write_all(&[1u8; 8]).unwrap();
+        assert_eq!(exact.pos(), 8);
+        assert!(exact.is_eof());
+        assert_eq!(
+            exact.write(&[1u8]).unwrap_err().kind(),
+            io::ErrorKind::WriteZero
+        );
+
+        let mut over = ConfinedIo::<_, 8>::from(Vec::<u8>::new());
+        over.write_all(&[1u8; 5]).unwrap();
+        assert_eq!(
+            over.write_all(&[2u8; 4]).unwrap_err().kind(),
+            io::ErrorKind::WriteZero
+        );
+        assert_eq!(over.pos(), 5);
+        assert_eq!(over.into_io(), vec![1u8; 5]);
+    }
+}
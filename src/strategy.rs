@@ -80,7 +80,12 @@
 //! }
 //! ```
 
+use ::core::fmt::{self, Display, Formatter};
 use ::core::marker::PhantomData;
+use ::core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Helper type allowing implementation of trait object for generic types
 /// multiple times. In practice this type is never used
@@ -98,3 +103,100 @@ impl<T, S> Holder<T, S> {
         &self.0
     }
 }
+
+/// Formats the held value; the strategy marker `S` is phantom and does not
+/// affect the formatting.
+impl<T, S> Display for Holder<T, S>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// Parses the held value; the strategy marker `S` is phantom and does not
+/// affect the parsing.
+impl<T, S> FromStr for Holder<T, S>
+where
+    T: FromStr,
+{
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str(s).map(Self::new)
+    }
+}
+
+/// Strategy marker for [`Holder`] serializing and deserializing the held type
+/// `T` through its conversion to and from type `U`, similar to serde
+/// `#[serde(into = "U", from = "U")]` attributes.
+///
+/// Serialization clones the held value and converts it into `U`;
+/// deserialization reads `U` and converts it into `T`.
+pub struct FromInto<U>(PhantomData<U>);
+
+#[cfg(feature = "serde")]
+impl<T, U> Serialize for Holder<T, FromInto<U>>
+where
+    T: Clone + Into<U>,
+    U: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr: U = self.0.clone().into();
+        repr.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, U> Deserialize<'de> for Holder<T, FromInto<U>>
+where
+    T: From<U>,
+    U: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        U::deserialize(deserializer).map(|repr| Holder::new(T::from(repr)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct SomeStrategy;
+
+    #[test]
+    fn display_from_str() {
+        let holder = Holder::<u32, SomeStrategy>::new(42);
+        assert_eq!(holder.to_string(), "42");
+        assert_eq!(format!("{:>4}", holder), "  42");
+        assert_eq!(Holder::<&u32, SomeStrategy>::new(&7).to_string(), "7");
+
+        let parsed = Holder::<u32, SomeStrategy>::from_str("42").unwrap();
+        assert_eq!(parsed.as_type(), holder.as_type());
+        assert!(Holder::<u32, SomeStrategy>::from_str("x").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_from_into() {
+        #[derive(Clone, PartialEq, Debug)]
+        struct Celsius(i16);
+        impl From<Celsius> for String {
+            fn from(c: Celsius) -> Self {
+                format!("{}C", c.0)
+            }
+        }
+        impl From<String> for Celsius {
+            fn from(s: String) -> Self {
+                Celsius(s.trim_end_matches('C').parse().unwrap())
+            }
+        }
+
+        let holder = Holder::<_, FromInto<String>>::new(Celsius(-5));
+        let json = serde_json::to_string(&holder).unwrap();
+        assert_eq!(json, r#""-5C""#);
+        let holder: Holder<Celsius, FromInto<String>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(holder.as_type(), &Celsius(-5));
+    }
+}
@@ -16,6 +16,8 @@
 use core::any::Any;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(any(test, feature = "alloc"))]
+use alloc::boxed::Box;
 
 /// Trait `AsAny` allows simple conversion of any type into a generic "thick"
 /// pointer `&dyn Any` (see [`Any`]), that can be later converted
@@ -148,11 +150,141 @@ impl AsAny for String {
     }
 }
 
+/// Trait `IntoAny` is an owning companion to [`AsAny`]: it converts a boxed
+/// value into a type-erased `Box<dyn Any>`, which can be later downcasted back
+/// to the original type by value.
+///
+/// # Example
+///
+/// ```
+/// use std::any::Any;
+///
+/// use amplify::IntoAny;
+///
+/// #[derive(PartialEq, Debug)]
+/// struct Point {
+///     pub x: u64,
+///     pub y: u64,
+/// }
+///
+/// impl IntoAny for Point {
+///     fn into_any(self: Box<Self>) -> Box<dyn Any> {
+///         self
+///     }
+/// }
+///
+/// let any = Box::new(Point { x: 1, y: 2 }).into_any();
+/// let point: Point = *any.downcast().unwrap();
+/// assert_eq!(point, Point { x: 1, y: 2 });
+/// ```
+#[cfg(any(test, feature = "alloc"))]
+pub trait IntoAny {
+    /// Converts boxed value into `Box<dyn Any>`, which can be later downcasted
+    /// back to the original type.
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for usize {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for u8 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for u16 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for u32 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for u64 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for u128 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for i8 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for i16 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for i32 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for i64 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for i128 {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl IntoAny for String {
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ::core::any::Any;
 
-    use super::AsAny;
+    use super::{AsAny, IntoAny};
 
     #[test]
     fn test_as_any_correct() {
@@ -184,4 +316,15 @@ mod test {
         assert!(1i128.as_any().downcast_ref::<u128>().is_none());
         assert!(s!("str").as_any().downcast_ref::<&str>().is_none());
     }
+
+    #[test]
+    fn test_into_any() {
+        let any = Box::new(42u64).into_any();
+        let any = any.downcast::<u32>().unwrap_err();
+        let value: u64 = *any.downcast().unwrap();
+        assert_eq!(value, 42);
+
+        let any = Box::new(s!("string")).into_any();
+        assert_eq!(*any.downcast::<String>().unwrap(), "string");
+    }
 }
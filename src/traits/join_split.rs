@@ -28,3 +28,50 @@ pub trait JoinSplit {
     /// Split the type into two
     fn split(self) -> (Self::A, Self::B);
 }
+
+impl<A, B> JoinSplit for (A, B) {
+    type A = A;
+    type B = B;
+
+    fn join(left: A, right: B) -> Self {
+        (left, right)
+    }
+
+    fn split(self) -> (A, B) {
+        self
+    }
+}
+
+/// Three-element tuples are split into the first element and a tuple of the
+/// remaining two.
+impl<A, B, C> JoinSplit for (A, B, C) {
+    type A = A;
+    type B = (B, C);
+
+    fn join(left: A, right: (B, C)) -> Self {
+        (left, right.0, right.1)
+    }
+
+    fn split(self) -> (A, (B, C)) {
+        (self.0, (self.1, self.2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tuples() {
+        let pair = (0xBEEFu16, 0xDEADBEEFu32);
+        let (a, b) = pair.split();
+        assert_eq!((a, b), (0xBEEF, 0xDEADBEEF));
+        assert_eq!(<(u16, u32)>::join(a, b), pair);
+
+        let triple = (1u8, "two", 3.0f32);
+        let (a, b) = triple.split();
+        assert_eq!(a, 1);
+        assert_eq!(b, ("two", 3.0));
+        assert_eq!(<(u8, &str, f32)>::join(a, b), triple);
+    }
+}
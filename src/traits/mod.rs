@@ -22,8 +22,10 @@ mod join_split;
 mod raw_str;
 
 pub use as_any::AsAny;
+#[cfg(feature = "alloc")]
+pub use as_any::IntoAny;
 pub use join_split::JoinSplit;
 pub use wrapper::{Wrapper, WrapperMut};
 pub use dumb::Dumb;
 #[cfg(feature = "c_raw")]
-pub use raw_str::{TryFromRawStr, TryAsStr, TryIntoRawStr, TryIntoString};
+pub use raw_str::{RawStrError, TryFromRawStr, TryAsStr, TryIntoRawStr, TryIntoString};
@@ -15,31 +15,63 @@
 
 #![allow(missing_docs, clippy::missing_safety_doc)]
 
+use core::fmt::{self, Display, Formatter};
 use libc::c_char;
 use std::ffi::{CStr, CString};
 
+/// Errors converting between Rust strings and raw C strings.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RawStrError {
+    /// The string contains an interior NUL byte at the given position.
+    InteriorNul(usize),
+
+    /// The C string is not a valid UTF-8 string; the invalid byte sequence
+    /// starts at the given offset.
+    InvalidUtf8(usize),
+}
+
+impl Display for RawStrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RawStrError::InteriorNul(pos) => {
+                write!(f, "string contains interior NUL byte at position {pos}")
+            }
+            RawStrError::InvalidUtf8(pos) => {
+                write!(
+                    f,
+                    "C string contains invalid UTF-8 sequence at offset {pos}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RawStrError {}
+
 pub trait TryIntoRawStr {
-    fn try_into_raw_str(self) -> Option<*const c_char>;
+    fn try_into_raw_str(self) -> Result<*const c_char, RawStrError>;
 }
 
 pub trait TryFromRawStr {
-    unsafe fn try_from_raw_str(ptr: *mut c_char) -> Option<Self>
+    unsafe fn try_from_raw_str(ptr: *mut c_char) -> Result<Self, RawStrError>
     where
         Self: Sized;
 }
 
 impl TryIntoRawStr for String {
-    fn try_into_raw_str(self) -> Option<*const c_char> {
+    fn try_into_raw_str(self) -> Result<*const c_char, RawStrError> {
         CString::new(self)
             .map(CString::into_raw)
             .map(|ptr| ptr as *const c_char)
-            .ok()
+            .map_err(|err| RawStrError::InteriorNul(err.nul_position()))
     }
 }
 
 impl TryFromRawStr for String {
-    unsafe fn try_from_raw_str(ptr: *mut c_char) -> Option<String> {
-        unsafe { CString::from_raw(ptr) }.into_string().ok()
+    unsafe fn try_from_raw_str(ptr: *mut c_char) -> Result<String, RawStrError> {
+        unsafe { CString::from_raw(ptr) }
+            .into_string()
+            .map_err(|err| RawStrError::InvalidUtf8(err.utf8_error().valid_up_to()))
     }
 }
 
@@ -65,6 +97,37 @@ impl TryIntoString for *mut c_char {
         if self.is_null() {
             return None;
         }
-        unsafe { String::try_from_raw_str(self) }
+        unsafe { String::try_from_raw_str(self) }.ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn interior_nul() {
+        assert_eq!(
+            s!("ab\0c").try_into_raw_str(),
+            Err(RawStrError::InteriorNul(2))
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let ptr = CString::new(vec![b'a', b'b', 0xFF, b'c'])
+            .unwrap()
+            .into_raw();
+        assert_eq!(
+            unsafe { String::try_from_raw_str(ptr) },
+            Err(RawStrError::InvalidUtf8(2))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let ptr = s!("raw string").try_into_raw_str().unwrap();
+        let s = unsafe { String::try_from_raw_str(ptr as *mut c_char) }.unwrap();
+        assert_eq!(s, "raw string");
     }
 }
@@ -41,9 +41,27 @@ pub trait Wrapper {
         self.as_inner().clone()
     }
 
+    /// Copies inner data of the wrapped type and return them
+    #[inline]
+    fn copy_inner(&self) -> Self::Inner
+    where
+        Self::Inner: Copy,
+    {
+        *self.as_inner()
+    }
+
     /// Unwraps the wrapper returning the inner type
     fn into_inner(self) -> Self::Inner;
 
+    /// Transforms the inner data with a function and wraps the result back
+    #[inline]
+    fn map_inner<F: FnOnce(Self::Inner) -> Self::Inner>(self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_inner(f(self.into_inner()))
+    }
+
     /// Copies the wrapped type
     fn copy(&self) -> Self
     where
@@ -97,4 +115,13 @@ mod test {
         assert_eq!(item, copy);
         assert_eq!(copy.into_inner(), 5)
     }
+
+    #[test]
+    fn test_map_copy_inner() {
+        let item = TestWrapper::from_inner(5);
+        assert_eq!(item.copy_inner(), 5);
+        let mapped = item.map_inner(|inner| inner * 2);
+        assert_eq!(mapped, TestWrapper(10));
+        assert_eq!(mapped.map_inner(u8::reverse_bits).into_inner(), 0b0101_0000);
+    }
 }